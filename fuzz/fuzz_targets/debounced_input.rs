@@ -0,0 +1,64 @@
+#![no_main]
+
+use embedded_controls::{
+    debounced_input_config,
+    testing::{ManualClock, ManualTimestamp, ScriptedInputSwitch},
+    Control, DebouncedInput, DebouncedInputEvent,
+};
+use libfuzzer_sys::fuzz_target;
+use timestamp_source::Timer;
+
+debounced_input_config!(
+    FuzzDebouncedInputConfig,
+    debounce_timer: Timer<ManualTimestamp> = Timer::new(8),
+    max_read_errors: 2
+);
+
+// Each byte is one update: bit 0 is the level, bit 1 is a read error, bits 2..8 advance the clock.
+fuzz_target!(|data: &[u8]| {
+    ManualClock::reset();
+
+    let mut ticks = 0;
+    let waveform = data.iter().map(|byte| {
+        let point = (
+            ticks,
+            if byte & 2 != 0 {
+                Err(())
+            } else {
+                Ok(byte & 1 != 0)
+            },
+        );
+        ticks += (byte >> 2) as u64;
+        point
+    });
+    let mut debounced_input = DebouncedInput::<_, FuzzDebouncedInputConfig>::new_unknown(
+        ScriptedInputSwitch::with_results(waveform),
+    );
+
+    let mut high = None;
+    for byte in data {
+        let event = match debounced_input.update() {
+            Ok(event) => event,
+            Err(_) => {
+                ManualClock::advance((byte >> 2) as u64);
+                continue;
+            }
+        };
+
+        match event {
+            DebouncedInputEvent::Rise => assert_ne!(high, Some(true)),
+            DebouncedInputEvent::Fall => assert_ne!(high, Some(false)),
+            DebouncedInputEvent::High => assert!(debounced_input.is_settled()),
+            DebouncedInputEvent::Low => {}
+        }
+        assert_eq!(
+            debounced_input.is_high(),
+            matches!(event, DebouncedInputEvent::Rise | DebouncedInputEvent::High)
+        );
+        if debounced_input.is_settled() {
+            high = Some(debounced_input.is_high());
+        }
+
+        ManualClock::advance((byte >> 2) as u64);
+    }
+});
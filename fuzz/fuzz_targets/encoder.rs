@@ -0,0 +1,39 @@
+#![no_main]
+
+use embedded_controls::{
+    encoder_config,
+    testing::{ManualClock, ManualTimestamp, ScriptedInputSwitch},
+    Control, Encoder,
+};
+use libfuzzer_sys::fuzz_target;
+use timestamp_source::Timer;
+
+encoder_config!(
+    FuzzEncoderConfig,
+    debounce_timer: Timer<ManualTimestamp> = Timer::new(2),
+    counts_div: i8 = 4
+);
+
+// Each byte is one update: bits 0 and 1 are levels of A and B, bits 2..8 advance the clock.
+fuzz_target!(|data: &[u8]| {
+    ManualClock::reset();
+
+    let mut ticks = 0;
+    let mut waveform_a = Vec::with_capacity(data.len());
+    let mut waveform_b = Vec::with_capacity(data.len());
+    for byte in data {
+        waveform_a.push((ticks, byte & 1 != 0));
+        waveform_b.push((ticks, byte & 2 != 0));
+        ticks += (byte >> 2) as u64;
+    }
+
+    let mut encoder = Encoder::<_, _, FuzzEncoderConfig>::new(
+        ScriptedInputSwitch::new(waveform_a),
+        ScriptedInputSwitch::new(waveform_b),
+    );
+
+    for byte in data {
+        encoder.update().unwrap();
+        ManualClock::advance((byte >> 2) as u64);
+    }
+});
@@ -0,0 +1,217 @@
+use crate::{Control, DebouncedInputConfig, Error};
+
+use core::marker::PhantomData;
+use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// The coding of the code that read from [`AbsoluteEncoder`](crate::AbsoluteEncoder) inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbsoluteEncoderCoding {
+    /// Plain binary code, the first input is the least significant bit.
+    Binary,
+    /// Reflected binary (Gray) code, the first input is the least significant bit.
+    Gray,
+    /// Binary-coded decimal, every 4 inputs represent one decimal digit,
+    /// the first input is the least significant bit of the least significant digit.
+    Bcd,
+}
+
+/// Represents a config for [`AbsoluteEncoder`](crate::AbsoluteEncoder).
+pub trait AbsoluteEncoderConfig: DebouncedInputConfig {
+    /// The coding of the encoder inputs.
+    const CODING: AbsoluteEncoderCoding;
+}
+
+/// The state machine of [`AbsoluteEncoder`](crate::AbsoluteEncoder).
+pub enum AbsoluteEncoderState<T> {
+    Fixed,
+    Disturbance(u32, T),
+}
+
+/// Concrete implementation of absolute encoder (e.g. rotary selector switch or Gray/BCD-coded encoder).
+///
+/// All inputs are debounced together: a new code is accepted only after it stays unchanged
+/// for [`DEBOUNCE_TIMER`](crate::DebouncedInputConfig::DEBOUNCE_TIMER),
+/// so intermediate codes during a transition are not reported.
+/// Stable codes that aren't valid for configured coding are ignored.
+///
+/// # Type Params
+/// `Switch` - [`InputSwitch`](switch_hal::InputSwitch) that provides input of one code bit.
+///
+/// `N` - The number of code bits, must be not more than 32.
+///
+/// `Config` - [`AbsoluteEncoderConfig`](crate::AbsoluteEncoderConfig) that provides configs for absolute encoder.
+///
+/// # Example
+/// ```ignore
+/// absolute_encoder_config!(
+///     SomeAbsoluteEncoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(10.millis()),
+///     coding: AbsoluteEncoderCoding::Gray
+/// );
+///
+/// type MyAbsoluteEncoder<Switch> = AbsoluteEncoder<Switch, 4, SomeAbsoluteEncoderConfig>;
+///
+/// let mut absolute_encoder = MyAbsoluteEncoder::new([
+///     pin_0.into_active_low_switch(),
+///     pin_1.into_active_low_switch(),
+///     pin_2.into_active_low_switch(),
+///     pin_3.into_active_low_switch(),
+/// ]);
+///
+/// loop {
+///     match absolute_encoder.update().unwrap() {
+///         AbsoluteEncoderEvent::NoChange => do_something_when_no_change(),
+///         AbsoluteEncoderEvent::PositionChanged(position) => do_something_upon_change(position),
+///     }
+/// }
+/// ```
+pub struct AbsoluteEncoder<Switch: InputSwitch, const N: usize, Config: AbsoluteEncoderConfig> {
+    input_switches: [Switch; N],
+    code: u32,
+    state: AbsoluteEncoderState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    config: PhantomData<Config>,
+}
+
+/// The event result of update [`AbsoluteEncoder`](crate::AbsoluteEncoder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbsoluteEncoderEvent {
+    /// Position of encoder isn't changed.
+    NoChange,
+    /// Position of encoder is changed to the new stable position.
+    PositionChanged(u32),
+}
+
+impl AbsoluteEncoderCoding {
+    /// Decodes `code` to a position, returns `None` if `code` isn't valid for this coding.
+    pub fn decode(self, code: u32) -> Option<u32> {
+        match self {
+            AbsoluteEncoderCoding::Binary => Some(code),
+            AbsoluteEncoderCoding::Gray => {
+                let mut position = code;
+                let mut shift = 1;
+                while shift < u32::BITS {
+                    position ^= position >> shift;
+                    shift <<= 1;
+                }
+                Some(position)
+            }
+            AbsoluteEncoderCoding::Bcd => {
+                let mut position = 0u32;
+                let mut weight = 1u32;
+                let mut code = code;
+                while code != 0 {
+                    let digit = code & 0xF;
+                    if digit > 9 {
+                        return None;
+                    }
+                    position += digit * weight;
+                    weight = weight.saturating_mul(10);
+                    code >>= 4;
+                }
+                Some(position)
+            }
+        }
+    }
+}
+
+impl<Switch: InputSwitch, const N: usize, Config: AbsoluteEncoderConfig>
+    AbsoluteEncoder<Switch, N, Config>
+{
+    /// Creates a new [`AbsoluteEncoder<Switch, N, Config>`] from concretes `Switch`es.
+    ///
+    /// `input_switches` - concrete instances of `Switch`, the first one is the least significant bit.
+    pub fn new(input_switches: [Switch; N]) -> Self {
+        const { assert!(N <= u32::BITS as usize) };
+
+        let mut init_code = 0;
+        for (bit, input_switch) in input_switches.iter().enumerate() {
+            if input_switch.is_active().unwrap_or(false) {
+                init_code |= 1 << bit;
+            }
+        }
+
+        AbsoluteEncoder {
+            input_switches,
+            code: init_code,
+            state: AbsoluteEncoderState::Fixed,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Returns the stable raw code.
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Returns the stable position, `None` if the initial code isn't valid for configured coding.
+    pub fn position(&self) -> Option<u32> {
+        Config::CODING.decode(self.code())
+    }
+
+    /// Borrow `Switch`es.
+    pub fn borrow_input_switches(&self) -> &[Switch; N] {
+        &self.input_switches
+    }
+
+    /// Consumes `self` and release `Switch`es.
+    pub fn release_input_switches(self) -> [Switch; N] {
+        self.input_switches
+    }
+
+    fn read_code(&self) -> Result<u32, Switch::Error> {
+        let mut code = 0;
+        for (bit, input_switch) in self.input_switches.iter().enumerate() {
+            if input_switch.is_active()? {
+                code |= 1 << bit;
+            }
+        }
+        Ok(code)
+    }
+}
+
+impl<Switch: InputSwitch, const N: usize, Config: AbsoluteEncoderConfig> Control
+    for AbsoluteEncoder<Switch, N, Config>
+{
+    type Event = AbsoluteEncoderEvent;
+    type Error =
+        Error<<<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error, Switch::Error>;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+        let code = self.read_code().map_err(Error::InputSwitch)?;
+
+        Ok(match &self.state {
+            AbsoluteEncoderState::Fixed => {
+                if code != self.code {
+                    self.state = AbsoluteEncoderState::Disturbance(code, now);
+                }
+                AbsoluteEncoderEvent::NoChange
+            }
+            AbsoluteEncoderState::Disturbance(disturbance_code, start) => {
+                if code == self.code {
+                    self.state = AbsoluteEncoderState::Fixed;
+                    AbsoluteEncoderEvent::NoChange
+                } else if code != *disturbance_code {
+                    self.state = AbsoluteEncoderState::Disturbance(code, now);
+                    AbsoluteEncoderEvent::NoChange
+                } else if Config::DEBOUNCE_TIMER
+                    .timeout(start, &now)
+                    .map_err(Error::ElapsedTimer)?
+                {
+                    self.state = AbsoluteEncoderState::Fixed;
+
+                    match Config::CODING.decode(code) {
+                        Some(position) => {
+                            self.code = code;
+                            AbsoluteEncoderEvent::PositionChanged(position)
+                        }
+                        _ => AbsoluteEncoderEvent::NoChange,
+                    }
+                } else {
+                    AbsoluteEncoderEvent::NoChange
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{AnalogButtonsError, AnalogInput, Control, DebouncedInputConfig};
+
+use core::marker::PhantomData;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`AnalogButtons`](crate::AnalogButtons).
+pub trait AnalogButtonsConfig: DebouncedInputConfig {
+    /// Inclusive ranges `(min, max)` of raw values for every key, the index of key is the index of range.
+    /// Raw values out of all ranges mean that no key is pressed.
+    const THRESHOLDS: &'static [(u16, u16)];
+}
+
+/// The state machine of [`AnalogButtons`](crate::AnalogButtons).
+pub enum AnalogButtonsState<T> {
+    Fixed,
+    Disturbance(Option<usize>, T),
+}
+
+/// Concrete implementation of several buttons on one analog input through a resistor ladder.
+///
+/// The decoded key is debounced by the same rule as [`DebouncedInput`](crate::DebouncedInput):
+/// a new key is accepted only after it stays unchanged for
+/// [`DEBOUNCE_TIMER`](crate::DebouncedInputConfig::DEBOUNCE_TIMER).
+///
+/// # Type Params
+/// `Input` - [`AnalogInput`](crate::AnalogInput) that provides raw values of resistor ladder.
+///
+/// `Config` - [`AnalogButtonsConfig`](crate::AnalogButtonsConfig) that provides configs for analog buttons.
+///
+/// # Example
+/// ```ignore
+/// analog_buttons_config!(
+///     SomeAnalogButtonsConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     thresholds: &[(0, 100), (600, 800), (1500, 1800), (2400, 2700), (3200, 3500)]
+/// );
+///
+/// type MyAnalogButtons<Input> = AnalogButtons<Input, SomeAnalogButtonsConfig>;
+///
+/// let mut analog_buttons = MyAnalogButtons::new(adc_channel);
+///
+/// loop {
+///     match analog_buttons.update().unwrap() {
+///         AnalogButtonsEvent::NoKey => do_something_when_no_key(),
+///         AnalogButtonsEvent::Held(key) => do_something_when_held(key),
+///         AnalogButtonsEvent::Pressed(key) => do_something_upon_press(key),
+///         AnalogButtonsEvent::Released(key) => do_something_upon_release(key),
+///     }
+/// }
+/// ```
+pub struct AnalogButtons<Input: AnalogInput, Config: AnalogButtonsConfig> {
+    analog_input: Input,
+    key: Option<usize>,
+    state: AnalogButtonsState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    config: PhantomData<Config>,
+}
+
+/// The event result of update [`AnalogButtons`](crate::AnalogButtons).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalogButtonsEvent {
+    /// Stable state, no key is pressed.
+    NoKey,
+    /// Stable state, the key with index is held.
+    Held(usize),
+    /// The key with index is pressed.
+    Pressed(usize),
+    /// The key with index is released.
+    Released(usize),
+}
+
+impl<Input: AnalogInput, Config: AnalogButtonsConfig> AnalogButtons<Input, Config> {
+    /// Creates a new [`AnalogButtons<Input, Config>`] from a concrete `Input`.
+    ///
+    /// `analog_input` - an concrete instance of `Input`.
+    pub fn new(analog_input: Input) -> Self {
+        let init_key = analog_input.read().ok().and_then(Self::decode);
+
+        AnalogButtons {
+            analog_input,
+            key: init_key,
+            state: AnalogButtonsState::Fixed,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Decodes a raw value to the key index, returns `None` if no key is pressed.
+    pub fn decode(value: u16) -> Option<usize> {
+        Config::THRESHOLDS
+            .iter()
+            .position(|(min, max)| (*min..=*max).contains(&value))
+    }
+
+    /// Returns the stable pressed key.
+    pub fn key(&self) -> Option<usize> {
+        self.key
+    }
+
+    /// Borrow `Input`.
+    pub fn borrow_analog_input(&self) -> &Input {
+        &self.analog_input
+    }
+
+    /// Consumes `self` and release `Input`.
+    pub fn release_analog_input(self) -> Input {
+        self.analog_input
+    }
+
+    fn stable_event(&self) -> AnalogButtonsEvent {
+        match self.key {
+            Some(key) => AnalogButtonsEvent::Held(key),
+            None => AnalogButtonsEvent::NoKey,
+        }
+    }
+}
+
+impl<Input: AnalogInput, Config: AnalogButtonsConfig> Control for AnalogButtons<Input, Config> {
+    type Event = AnalogButtonsEvent;
+    type Error = AnalogButtonsError<
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+        Input::Error,
+    >;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+        let key = Self::decode(
+            self.analog_input
+                .read()
+                .map_err(AnalogButtonsError::AnalogInput)?,
+        );
+
+        let elapsed = match &self.state {
+            AnalogButtonsState::Disturbance(disturbance_key, start) if *disturbance_key == key => {
+                Config::DEBOUNCE_TIMER
+                    .timeout(start, &now)
+                    .map_err(AnalogButtonsError::ElapsedTimer)?
+            }
+            _ => false,
+        };
+
+        if key == self.key {
+            self.state = AnalogButtonsState::Fixed;
+            return Ok(self.stable_event());
+        }
+
+        if !elapsed {
+            if !matches!(&self.state, AnalogButtonsState::Disturbance(disturbance_key, _) if *disturbance_key == key)
+            {
+                self.state = AnalogButtonsState::Disturbance(key, now);
+            }
+            return Ok(self.stable_event());
+        }
+
+        Ok(match (self.key, key) {
+            (Some(released_key), Some(_)) => {
+                // Keep the disturbance to report the new key upon the next update.
+                self.key = None;
+                AnalogButtonsEvent::Released(released_key)
+            }
+            (Some(released_key), None) => {
+                self.key = None;
+                self.state = AnalogButtonsState::Fixed;
+                AnalogButtonsEvent::Released(released_key)
+            }
+            (None, Some(pressed_key)) => {
+                self.key = Some(pressed_key);
+                self.state = AnalogButtonsState::Fixed;
+                AnalogButtonsEvent::Pressed(pressed_key)
+            }
+            (None, None) => self.stable_event(),
+        })
+    }
+}
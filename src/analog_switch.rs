@@ -0,0 +1,91 @@
+use crate::AnalogInput;
+
+use core::cell::Cell;
+use switch_hal::InputSwitch;
+
+/// Adapter that implements [`InputSwitch`](switch_hal::InputSwitch) over [`AnalogInput`](crate::AnalogInput)
+/// with Schmitt trigger thresholds, so analog signals can be used by any digital control.
+///
+/// The switch becomes active when a raw value is not less than `upper_threshold`
+/// and becomes inactive when a raw value is not more than `lower_threshold`.
+///
+/// # Type Params
+/// `Input` - [`AnalogInput`](crate::AnalogInput) that provides raw values.
+///
+/// # Example
+/// ```ignore
+/// type MyDebouncedInput<Switch> = DebouncedInput<Switch, SomeDebouncedInputConfig>;
+///
+/// let mut debounced_input = MyDebouncedInput::new(AnalogSwitch::new(adc_channel, 1200, 1800));
+///
+/// loop {
+///     match debounced_input.update().unwrap() {
+///         DebouncedInputEvent::Rise => do_something_upon_rise(),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct AnalogSwitch<Input: AnalogInput> {
+    analog_input: Input,
+    lower_threshold: u16,
+    upper_threshold: u16,
+    active: Cell<bool>,
+}
+
+impl<Input: AnalogInput> AnalogSwitch<Input> {
+    /// Creates a new [`AnalogSwitch<Input>`] from a concrete `Input`, the switch is initially inactive.
+    ///
+    /// `analog_input` - an concrete instance of `Input`.
+    ///
+    /// `lower_threshold` - the switch becomes inactive at or below this raw value.
+    ///
+    /// `upper_threshold` - the switch becomes active at or above this raw value.
+    pub fn new(analog_input: Input, lower_threshold: u16, upper_threshold: u16) -> Self {
+        assert!(lower_threshold < upper_threshold);
+
+        AnalogSwitch {
+            analog_input,
+            lower_threshold,
+            upper_threshold,
+            active: Cell::new(false),
+        }
+    }
+
+    /// Returns the lower threshold.
+    pub fn lower_threshold(&self) -> u16 {
+        self.lower_threshold
+    }
+
+    /// Returns the upper threshold.
+    pub fn upper_threshold(&self) -> u16 {
+        self.upper_threshold
+    }
+
+    /// Borrow `Input`.
+    pub fn borrow_analog_input(&self) -> &Input {
+        &self.analog_input
+    }
+
+    /// Consumes `self` and release `Input`.
+    pub fn release_analog_input(self) -> Input {
+        self.analog_input
+    }
+}
+
+impl<Input: AnalogInput> InputSwitch for AnalogSwitch<Input> {
+    type Error = Input::Error;
+
+    fn is_active(&self) -> Result<bool, Self::Error> {
+        let value = self.analog_input.read()?;
+
+        if self.active.get() {
+            if value <= self.lower_threshold {
+                self.active.set(false);
+            }
+        } else if value >= self.upper_threshold {
+            self.active.set(true);
+        }
+
+        Ok(self.active.get())
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{Control, OutputError, ToneOutput};
+
+use switch_hal::OutputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// The step of [`BeepPattern`](crate::BeepPattern), the tone is played until the timer is elapsed.
+pub struct BeepStep<Timer: 'static> {
+    /// The frequency of the tone in Hz, `None` means silence.
+    pub frequency: Option<u32>,
+    /// Elapsed timer instance that defines the duration of the step.
+    pub timer: Timer,
+}
+
+/// The pattern of [`Beeper`](crate::Beeper), usually defined as a const table.
+///
+/// # Example
+/// ```ignore
+/// const CLICK: BeepPattern<MyElapsedTimer> = &[BeepStep::new(Some(4000), MyElapsedTimer::new(5.millis()))];
+///
+/// const CONFIRM: BeepPattern<MyElapsedTimer> = &[
+///     BeepStep::new(Some(2000), MyElapsedTimer::new(80.millis())),
+///     BeepStep::new(None, MyElapsedTimer::new(40.millis())),
+///     BeepStep::new(Some(3000), MyElapsedTimer::new(120.millis())),
+/// ];
+/// ```
+pub type BeepPattern<Timer> = &'static [BeepStep<Timer>];
+
+/// Adapter that implements [`ToneOutput`](crate::ToneOutput) over [`OutputSwitch`](switch_hal::OutputSwitch)
+/// for buzzers with a built-in generator, any frequency switches the output on.
+///
+/// # Type Params
+/// `Switch` - [`OutputSwitch`](switch_hal::OutputSwitch) that drives the buzzer.
+pub struct SwitchTone<Switch: OutputSwitch> {
+    output_switch: Switch,
+}
+
+/// Concrete implementation of beeper (buzzer, speaker and etc.) that plays queued beep patterns.
+///
+/// The tone is silenced for one update between queued patterns, so repeated patterns are heard separately.
+///
+/// If setting the tone is failed, patterns keep playing and the tone is set again upon the next update,
+/// [`BeeperEvent::Finished`] is reported once the tone is set.
+///
+/// # Type Params
+/// `Tone` - [`ToneOutput`](crate::ToneOutput) that drives the beeper,
+/// see [`SwitchTone`](crate::SwitchTone) for [`OutputSwitch`](switch_hal::OutputSwitch).
+///
+/// `Timer` - [`ElapsedTimer`](timestamp_source::ElapsedTimer) that is used by steps of patterns.
+///
+/// `N` - The maximum number of queued patterns.
+///
+/// # Example
+/// ```ignore
+/// const ENCODER_FEEDBACK: &[(EncoderEvent, BeepPattern<MyElapsedTimer>)] = &[
+///     (EncoderEvent::ClockwiseTurn, CLICK),
+///     (EncoderEvent::CounterClockwiseTurn, CLICK),
+/// ];
+///
+/// let mut beeper = Beeper::<_, MyElapsedTimer, 4>::new(SwitchTone::new(pin.into_active_high_switch()));
+///
+/// loop {
+///     let event = encoder.update().unwrap();
+///     beeper.feedback(&event, ENCODER_FEEDBACK);
+///
+///     if long_press_confirmed() {
+///         beeper.play(CONFIRM);
+///     }
+///
+///     beeper.update().unwrap();
+/// }
+/// ```
+pub struct Beeper<Tone: ToneOutput, Timer: ElapsedTimer + 'static, const N: usize> {
+    tone_output: Tone,
+    queue: [Option<BeepPattern<Timer>>; N],
+    head: usize,
+    len: usize,
+    step: usize,
+    step_start: Option<Timer::Timestamp>,
+    driven: Option<Option<u32>>,
+    finished: bool,
+}
+
+/// The event result of update [`Beeper`](crate::Beeper).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeeperEvent {
+    /// There are no queued patterns.
+    Idle,
+    /// A pattern is playing.
+    Playing,
+    /// A pattern is finished, the next queued pattern is started if any.
+    Finished,
+}
+
+impl<Timer> BeepStep<Timer> {
+    /// Creates a new [`BeepStep<Timer>`].
+    pub const fn new(frequency: Option<u32>, timer: Timer) -> Self {
+        BeepStep { frequency, timer }
+    }
+}
+
+impl<Switch: OutputSwitch> SwitchTone<Switch> {
+    /// Creates a new [`SwitchTone<Switch>`] from a concrete `Switch`.
+    ///
+    /// `output_switch` - an concrete instance of `Switch`.
+    pub fn new(output_switch: Switch) -> Self {
+        SwitchTone { output_switch }
+    }
+
+    /// Borrow `Switch`.
+    pub fn borrow_output_switch(&self) -> &Switch {
+        &self.output_switch
+    }
+
+    /// Consumes `self` and release `Switch`.
+    pub fn release_output_switch(self) -> Switch {
+        self.output_switch
+    }
+}
+
+impl<Switch: OutputSwitch> ToneOutput for SwitchTone<Switch> {
+    type Error = Switch::Error;
+
+    fn set_tone(&mut self, frequency: Option<u32>) -> Result<(), Self::Error> {
+        match frequency {
+            Some(_) => self.output_switch.on(),
+            None => self.output_switch.off(),
+        }
+    }
+}
+
+impl<Tone: ToneOutput, Timer: ElapsedTimer + 'static, const N: usize> Beeper<Tone, Timer, N> {
+    /// Creates a new [`Beeper<Tone, Timer, N>`] from a concrete `Tone`.
+    ///
+    /// `tone_output` - an concrete instance of `Tone`.
+    pub fn new(tone_output: Tone) -> Self {
+        Beeper {
+            tone_output,
+            queue: [None; N],
+            head: 0,
+            len: 0,
+            step: 0,
+            step_start: None,
+            driven: None,
+            finished: false,
+        }
+    }
+
+    /// Queues `pattern` to play after already queued patterns, an empty pattern is ignored.
+    ///
+    /// Returns false if the queue is full and the pattern is dropped.
+    pub fn play(&mut self, pattern: BeepPattern<Timer>) -> bool {
+        if pattern.is_empty() {
+            return true;
+        }
+
+        if self.len == N {
+            return false;
+        }
+
+        self.queue[(self.head + self.len) % N] = Some(pattern);
+        self.len += 1;
+        true
+    }
+
+    /// Queues the pattern bound to `event` in `bindings`, if any.
+    ///
+    /// Returns false if the queue is full and the pattern is dropped.
+    pub fn feedback<E: PartialEq>(
+        &mut self,
+        event: &E,
+        bindings: &[(E, BeepPattern<Timer>)],
+    ) -> bool {
+        match bindings.iter().find(|(bound, _)| bound == event) {
+            Some((_, pattern)) => self.play(pattern),
+            None => true,
+        }
+    }
+
+    /// Stops the playing pattern and removes all queued patterns, the tone is silenced upon the next update.
+    pub fn stop(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.step = 0;
+        self.step_start = None;
+        self.finished = false;
+    }
+
+    /// Returns the number of queued patterns including the playing one.
+    pub fn queued(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if a pattern is playing.
+    pub fn is_playing(&self) -> bool {
+        self.len > 0
+    }
+
+    /// Borrow `Tone`.
+    pub fn borrow_tone_output(&self) -> &Tone {
+        &self.tone_output
+    }
+
+    /// Consumes `self` and release `Tone`.
+    pub fn release_tone_output(self) -> Tone {
+        self.tone_output
+    }
+
+    fn pattern(&self) -> Option<BeepPattern<Timer>> {
+        match self.len {
+            0 => None,
+            _ => self.queue[self.head],
+        }
+    }
+
+    fn pop(&mut self) {
+        self.queue[self.head] = None;
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        self.step = 0;
+    }
+}
+
+impl<Tone: ToneOutput, Timer: ElapsedTimer + 'static, const N: usize> Control
+    for Beeper<Tone, Timer, N>
+{
+    type Event = BeeperEvent;
+    type Error = OutputError<<Timer::Timestamp as Timestamp>::Error, Tone::Error>;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let now = Timer::Timestamp::now();
+        let mut boundary = false;
+
+        if let Some(pattern) = self.pattern() {
+            match &self.step_start {
+                None => {
+                    self.step = 0;
+                    self.step_start = Some(now);
+                }
+                Some(step_start) => {
+                    if pattern[self.step]
+                        .timer
+                        .timeout(step_start, &now)
+                        .map_err(OutputError::ElapsedTimer)?
+                    {
+                        self.step += 1;
+                        if self.step >= pattern.len() {
+                            // the next pattern is started upon the next update after a silent gap
+                            self.pop();
+                            self.finished = true;
+                            self.step_start = None;
+                            boundary = true;
+                        } else {
+                            self.step_start = Some(now);
+                        }
+                    }
+                }
+            }
+        }
+
+        let frequency = match boundary {
+            true => None,
+            false => self
+                .pattern()
+                .and_then(|pattern| pattern[self.step].frequency),
+        };
+
+        if self.driven != Some(frequency) {
+            self.tone_output
+                .set_tone(frequency)
+                .map_err(OutputError::OutputSwitch)?;
+            self.driven = Some(frequency);
+        }
+
+        Ok(if core::mem::take(&mut self.finished) {
+            BeeperEvent::Finished
+        } else if self.is_playing() {
+            BeeperEvent::Playing
+        } else {
+            BeeperEvent::Idle
+        })
+    }
+}
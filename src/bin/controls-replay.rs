@@ -0,0 +1,168 @@
+//! Replays captured VCD or CSV pin traces through controls and prints the event stream.
+
+use embedded_controls::{
+    decode_record_entries,
+    replay::{
+        format_ticks, parse_duration, replay_debounced_input, replay_encoder, ReplayTimer, Trace,
+    },
+};
+
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+Usage: controls-replay <TRACE> [OPTIONS]
+
+Replays a VCD (.vcd), CSV (.csv) or recorder (.bin) trace through controls and prints events with time in seconds.
+A CSV trace has a header, the first column is time in seconds and other columns are levels 0 or 1.
+A recorder trace consists of encoded entries of Recorder, its signals are raw0..raw7.
+
+Options:
+    --input <SIGNAL>            replay SIGNAL through DebouncedInput
+    --encoder <SIGNAL_A> <SIGNAL_B>
+                                replay SIGNAL_A and SIGNAL_B through Encoder
+    --debounce <DURATION>       debounce timer, default 20ms
+    --counts-div <1|2|4>        counts div of Encoder, default 4
+    --period <DURATION>         update period of controls, default 1ms
+    --tick <DURATION>           tick of recorder trace, default 1ms
+    --list                      list signals of the trace
+
+Durations have suffix ns, us, ms or s, e.g. 500us.";
+
+enum Mode {
+    List,
+    Input(String),
+    Encoder(String, String),
+}
+
+struct Args {
+    trace: String,
+    mode: Mode,
+    debounce: u64,
+    counts_div: u32,
+    period: u64,
+    tick: u64,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut trace = None;
+    let mut mode = None;
+    let mut debounce = parse_duration("20ms");
+    let mut counts_div = 4;
+    let mut period = parse_duration("1ms");
+    let mut tick = parse_duration("1ms");
+
+    let duration = |value: Option<String>, option: &str| {
+        value
+            .as_deref()
+            .and_then(parse_duration)
+            .ok_or_else(|| format!("invalid duration of {}", option))
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => mode = Some(Mode::Input(args.next().ok_or("missing --input signal")?)),
+            "--encoder" => {
+                let a = args.next().ok_or("missing --encoder signals")?;
+                let b = args.next().ok_or("missing --encoder signals")?;
+                mode = Some(Mode::Encoder(a, b));
+            }
+            "--list" => mode = Some(Mode::List),
+            "--debounce" => debounce = Some(duration(args.next(), "--debounce")?),
+            "--period" => period = Some(duration(args.next(), "--period")?),
+            "--tick" => tick = Some(duration(args.next(), "--tick")?),
+            "--counts-div" => {
+                counts_div = args
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or("invalid --counts-div")?
+            }
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+            _ => trace = Some(arg),
+        }
+    }
+
+    Ok(Args {
+        trace: trace.ok_or("missing trace")?,
+        mode: mode.ok_or("missing --input, --encoder or --list")?,
+        debounce: debounce.unwrap_or_default(),
+        counts_div,
+        period: period.filter(|period| *period > 0).ok_or("zero --period")?,
+        tick: tick.unwrap_or_default(),
+    })
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let source = fs::read(&args.trace).map_err(|error| error.to_string())?;
+    let trace = if args.trace.ends_with(".bin") {
+        Trace::from_record_entries(
+            decode_record_entries(&source),
+            &[
+                "raw0", "raw1", "raw2", "raw3", "raw4", "raw5", "raw6", "raw7",
+            ],
+            args.tick,
+        )
+    } else {
+        let source = String::from_utf8(source).map_err(|error| error.to_string())?;
+
+        if args.trace.ends_with(".csv") {
+            Trace::parse_csv(&source)
+        } else {
+            Trace::parse_vcd(&source)
+        }
+        .map_err(|error| error.to_string())?
+    };
+
+    ReplayTimer::set_duration(args.debounce);
+    let until = trace.end() + args.debounce + 2 * args.period;
+
+    let events: Vec<(u64, String)> = match &args.mode {
+        Mode::List => {
+            for name in trace.names() {
+                println!("{}", name);
+            }
+            return Ok(());
+        }
+        Mode::Input(name) => {
+            let waveform = trace.waveform(name).map_err(|error| error.to_string())?;
+
+            replay_debounced_input(waveform, args.period, until)
+                .map_err(|error| error.to_string())?
+                .into_iter()
+                .map(|(ticks, event)| (ticks, format!("{:?}", event)))
+                .collect()
+        }
+        Mode::Encoder(name_a, name_b) => {
+            let waveform_a = trace.waveform(name_a).map_err(|error| error.to_string())?;
+            let waveform_b = trace.waveform(name_b).map_err(|error| error.to_string())?;
+
+            replay_encoder(waveform_a, waveform_b, args.counts_div, args.period, until)
+                .map_err(|error| error.to_string())?
+                .into_iter()
+                .map(|(ticks, event)| (ticks, format!("{:?}", event)))
+                .collect()
+        }
+    };
+
+    for (ticks, event) in &events {
+        println!("{} {}", format_ticks(*ticks), event);
+    }
+    eprintln!("{} events", events.len());
+
+    Ok(())
+}
+
+fn main() {
+    let result = parse_args(env::args().skip(1))
+        .and_then(|args| run(args).map_err(|error| format!("error: {}", error)));
+
+    if let Err(error) = result {
+        if error.is_empty() {
+            println!("{}", USAGE);
+            return;
+        }
+
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{Control, DebouncedInput, DebouncedInputConfig, DebouncedInputEvent, Error};
+
+use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`Chord`](crate::Chord).
+pub trait ChordConfig: DebouncedInputConfig {
+    /// Elapsed timer instance that used for [`Chord`](crate::Chord).
+    /// All keys of a chord must be pressed before this timer is elapsed since the first key press.
+    const SIMULTANEITY_TIMER: Self::Timer;
+}
+
+/// The state machine of [`Chord`](crate::Chord).
+pub enum ChordState<T> {
+    Idle,
+    Collecting(u32, T),
+    Single(usize),
+    Chorded(u32),
+}
+
+/// Concrete implementation of chord detector (key combinations) over several debounced inputs.
+///
+/// Keys pressed within [`SIMULTANEITY_TIMER`](crate::ChordConfig::SIMULTANEITY_TIMER)
+/// since the first press form a chord, individual key events of these keys are suppressed.
+/// A key pressed alone is reported after the simultaneity window is elapsed or after it's released.
+/// Keys pressed while a single key is held extend it into a chord with a new simultaneity window,
+/// keys pressed while a chord is held are ignored.
+///
+/// All inputs are updated even if some of them fail, the first error is returned
+/// and keys pressed during the failed update are handled upon the next successful update.
+///
+/// # Type Params
+/// `Switch` - [`InputSwitch`](switch_hal::InputSwitch) that provides input of one key.
+///
+/// `N` - The number of keys, must be not more than 32.
+///
+/// `Config` - [`ChordConfig`](crate::ChordConfig) that provides configs for chord detector.
+///
+/// # Example
+/// ```ignore
+/// chord_config!(
+///     SomeChordConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(200.millis())
+/// );
+///
+/// type MyChord<Switch> = Chord<Switch, 3, SomeChordConfig>;
+///
+/// const MENU: u32 = 1 << 0;
+/// const UP: u32 = 1 << 1;
+///
+/// let mut chord = MyChord::new([
+///     pin_menu.into_active_low_switch(),
+///     pin_up.into_active_low_switch(),
+///     pin_down.into_active_low_switch(),
+/// ]);
+///
+/// loop {
+///     match chord.update().unwrap() {
+///         ChordEvent::NoEvent => {}
+///         ChordEvent::KeyPressed(key) => do_something_upon_key_press(key),
+///         ChordEvent::KeyReleased(key) => do_something_upon_key_release(key),
+///         ChordEvent::Chord(mask) if mask == MENU | UP => do_something_hidden(),
+///         ChordEvent::Chord(_) | ChordEvent::ChordReleased(_) => {}
+///     }
+/// }
+/// ```
+pub struct Chord<Switch: InputSwitch, const N: usize, Config: ChordConfig> {
+    debounced_inputs: [DebouncedInput<Switch, Config>; N],
+    state: ChordState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    pending_rise_mask: u32,
+}
+
+/// The event result of update [`Chord`](crate::Chord).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChordEvent {
+    /// No key or chord event.
+    NoEvent,
+    /// Single key with index is pressed.
+    KeyPressed(usize),
+    /// Single key with index is released.
+    KeyReleased(usize),
+    /// Chord is pressed, contains the mask of keys (bit per key index).
+    Chord(u32),
+    /// All keys of chord are released, contains the mask of keys (bit per key index).
+    ChordReleased(u32),
+}
+
+impl<Switch: InputSwitch, const N: usize, Config: ChordConfig> Chord<Switch, N, Config> {
+    /// Creates a new [`Chord<Switch, N, Config>`] from concretes `Switch`es.
+    ///
+    /// `input_switches` - concrete instances of `Switch`, the index of key is the index in the array.
+    pub fn new(input_switches: [Switch; N]) -> Self {
+        const { assert!(N <= u32::BITS as usize) };
+
+        Chord {
+            debounced_inputs: input_switches.map(DebouncedInput::new),
+            state: ChordState::Idle,
+            pending_rise_mask: 0,
+        }
+    }
+
+    /// Returns the mask of stable pressed keys (bit per key index).
+    pub fn pressed_mask(&self) -> u32 {
+        self.debounced_inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, debounced_input)| debounced_input.is_high())
+            .fold(0, |mask, (key, _)| mask | 1 << key)
+    }
+
+    /// Borrow debounced inputs of keys.
+    pub fn borrow_debounced_inputs(&self) -> &[DebouncedInput<Switch, Config>; N] {
+        &self.debounced_inputs
+    }
+
+    /// Consumes `self` and release `Switch`es.
+    pub fn release_input_switches(self) -> [Switch; N] {
+        self.debounced_inputs
+            .map(DebouncedInput::release_input_switch)
+    }
+}
+
+impl<Switch: InputSwitch, const N: usize, Config: ChordConfig> Control
+    for Chord<Switch, N, Config>
+{
+    type Event = ChordEvent;
+    type Error = <DebouncedInput<Switch, Config> as Control>::Error;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let mut rise_mask = core::mem::take(&mut self.pending_rise_mask);
+        let mut result = Ok(());
+        for (key, debounced_input) in self.debounced_inputs.iter_mut().enumerate() {
+            match debounced_input.update() {
+                Ok(DebouncedInputEvent::Rise) => rise_mask |= 1 << key,
+                Ok(_) => {}
+                Err(error) => result = result.and(Err(error)),
+            }
+        }
+        if let Err(error) = result {
+            self.pending_rise_mask = rise_mask;
+            return Err(error);
+        }
+
+        let pressed_mask = self.pressed_mask();
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        Ok(match &self.state {
+            ChordState::Idle => {
+                if rise_mask != 0 {
+                    self.state = ChordState::Collecting(rise_mask, now);
+                }
+                ChordEvent::NoEvent
+            }
+            ChordState::Collecting(mask, start) => {
+                let mask = mask | rise_mask;
+
+                if mask & !pressed_mask != 0
+                    || Config::SIMULTANEITY_TIMER
+                        .timeout(start, &now)
+                        .map_err(Error::ElapsedTimer)?
+                {
+                    if mask.count_ones() > 1 {
+                        self.state = ChordState::Chorded(mask);
+                        ChordEvent::Chord(mask)
+                    } else {
+                        let key = mask.trailing_zeros() as usize;
+                        self.state = ChordState::Single(key);
+                        ChordEvent::KeyPressed(key)
+                    }
+                } else {
+                    if let ChordState::Collecting(collecting_mask, _) = &mut self.state {
+                        *collecting_mask = mask;
+                    }
+                    ChordEvent::NoEvent
+                }
+            }
+            ChordState::Single(key) => {
+                let key = *key;
+                if rise_mask != 0 && pressed_mask & 1 << key != 0 {
+                    self.state = ChordState::Collecting(1 << key | rise_mask, now);
+                    ChordEvent::NoEvent
+                } else if pressed_mask & 1 << key == 0 {
+                    self.state = ChordState::Idle;
+                    ChordEvent::KeyReleased(key)
+                } else {
+                    ChordEvent::NoEvent
+                }
+            }
+            ChordState::Chorded(mask) => {
+                let mask = *mask;
+                if pressed_mask & mask == 0 {
+                    self.state = ChordState::Idle;
+                    ChordEvent::ChordReleased(mask)
+                } else {
+                    ChordEvent::NoEvent
+                }
+            }
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Control, Error};
+use crate::{Control, Error, RawLevels};
 
 use core::marker::PhantomData;
 use switch_hal::InputSwitch;
@@ -12,14 +12,22 @@ pub trait DebouncedInputConfig {
     /// Elapsed timer instance that used for [`DebouncedInput`](crate::DebouncedInput).
     /// This timer is used for debounce of input by timeout after disturbance start.
     const DEBOUNCE_TIMER: Self::Timer;
+
+    /// The number of consecutive read errors that are tolerated by [`DebouncedInput`](crate::DebouncedInput).
+    /// A tolerated error is treated as disturbance, the error is surfaced if the number is exceeded.
+    const MAX_READ_ERRORS: u8 = 0;
 }
 
 /// The state machine of [`DebouncedInput`](crate::DebouncedInput).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DebouncedInputState<T> {
     FixedLow,
     FixedHigh,
     RiseDisturbance(T),
     FallDisturbance(T),
+    Unknown,
+    Settling(bool, T),
 }
 
 /// Concrete implementation of debounced input.
@@ -52,11 +60,15 @@ pub enum DebouncedInputState<T> {
 pub struct DebouncedInput<Switch: InputSwitch, Config: DebouncedInputConfig> {
     input_switch: Switch,
     state: DebouncedInputState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    read_errors: u8,
+    raw_level: bool,
     config: PhantomData<Config>,
 }
 
 /// The event result of update [`DebouncedInput`](crate::DebouncedInput).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DebouncedInputEvent {
     /// Stable low state, the input is inactive.
     Low,
@@ -73,7 +85,8 @@ impl<Switch: InputSwitch, Config: DebouncedInputConfig> DebouncedInput<Switch, C
     ///
     /// `input_switch` - an concrete instance of `Switch`.
     pub fn new(input_switch: Switch) -> Self {
-        let init_state = if input_switch.is_active().unwrap_or(false) {
+        let raw_level = input_switch.is_active().unwrap_or(false);
+        let init_state = if raw_level {
             DebouncedInputState::FixedHigh
         } else {
             DebouncedInputState::FixedLow
@@ -82,6 +95,47 @@ impl<Switch: InputSwitch, Config: DebouncedInputConfig> DebouncedInput<Switch, C
         DebouncedInput {
             input_switch,
             state: init_state,
+            read_errors: 0,
+            raw_level,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Creates a new [`DebouncedInput<Switch, Config>`] from a concrete `Switch`,
+    /// unlike [`new`](DebouncedInput::new) it reports an error of the initial read.
+    ///
+    /// `input_switch` - an concrete instance of `Switch`.
+    /// # Errors
+    /// This function will return an error if the initial read of `input_switch` is failed.
+    pub fn try_new(input_switch: Switch) -> Result<Self, <Self as Control>::Error> {
+        let raw_level = input_switch.is_active().map_err(Error::InputSwitch)?;
+        let init_state = if raw_level {
+            DebouncedInputState::FixedHigh
+        } else {
+            DebouncedInputState::FixedLow
+        };
+
+        Ok(DebouncedInput {
+            input_switch,
+            state: init_state,
+            read_errors: 0,
+            raw_level,
+            config: PhantomData::<Config>,
+        })
+    }
+
+    /// Creates a new [`DebouncedInput<Switch, Config>`] from a concrete `Switch` with unknown initial state.
+    ///
+    /// The input isn't read upon creation, the initial state is settled by the first stable reading
+    /// without emitting any edge, [`DebouncedInputEvent::Low`] is reported until then.
+    ///
+    /// `input_switch` - an concrete instance of `Switch`.
+    pub fn new_unknown(input_switch: Switch) -> Self {
+        DebouncedInput {
+            input_switch,
+            state: DebouncedInputState::Unknown,
+            read_errors: 0,
+            raw_level: false,
             config: PhantomData::<Config>,
         }
     }
@@ -89,16 +143,42 @@ impl<Switch: InputSwitch, Config: DebouncedInputConfig> DebouncedInput<Switch, C
     /// Returns the is stable high state.
     pub fn is_high(&self) -> bool {
         match self.state {
-            DebouncedInputState::FixedLow | DebouncedInputState::RiseDisturbance(_) => false,
+            DebouncedInputState::FixedLow
+            | DebouncedInputState::RiseDisturbance(_)
+            | DebouncedInputState::Unknown
+            | DebouncedInputState::Settling(_, _) => false,
             DebouncedInputState::FixedHigh | DebouncedInputState::FallDisturbance(_) => true,
         }
     }
 
+    /// Returns true if the initial state is settled.
+    pub fn is_settled(&self) -> bool {
+        !matches!(
+            self.state,
+            DebouncedInputState::Unknown | DebouncedInputState::Settling(_, _)
+        )
+    }
+
     /// Returns the is stable low state.
     pub fn is_low(&self) -> bool {
         !self.is_high()
     }
 
+    /// Returns the raw level of the input read by the last successful read.
+    pub fn raw_level(&self) -> bool {
+        self.raw_level
+    }
+
+    /// Returns the number of consecutive read errors.
+    pub fn read_errors(&self) -> u8 {
+        self.read_errors
+    }
+
+    /// Borrow the state machine.
+    pub fn borrow_state(&self) -> &DebouncedInputState<<Config::Timer as ElapsedTimer>::Timestamp> {
+        &self.state
+    }
+
     /// Borrow `Switch`.
     pub fn borrow_input_switch(&self) -> &Switch {
         &self.input_switch
@@ -117,7 +197,38 @@ impl<Switch: InputSwitch, Config: DebouncedInputConfig> Control for DebouncedInp
 
     fn update(&mut self) -> Result<Self::Event, Self::Error> {
         let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
-        let input_switch_state = self.input_switch.is_active().map_err(Error::InputSwitch)?;
+        let input_switch_state = match self.input_switch.is_active() {
+            Ok(input_switch_state) => {
+                self.read_errors = 0;
+                self.raw_level = input_switch_state;
+                input_switch_state
+            }
+            Err(error) => {
+                if self.read_errors >= Config::MAX_READ_ERRORS {
+                    return Err(Error::InputSwitch(error));
+                }
+                self.read_errors += 1;
+
+                match self.state {
+                    DebouncedInputState::RiseDisturbance(_) => {
+                        self.state = DebouncedInputState::FixedLow
+                    }
+                    DebouncedInputState::FallDisturbance(_) => {
+                        self.state = DebouncedInputState::FixedHigh
+                    }
+                    DebouncedInputState::Settling(_, _) => {
+                        self.state = DebouncedInputState::Unknown
+                    }
+                    _ => {}
+                }
+
+                return Ok(if self.is_high() {
+                    DebouncedInputEvent::High
+                } else {
+                    DebouncedInputEvent::Low
+                });
+            }
+        };
 
         Ok(match &self.state {
             DebouncedInputState::FixedLow => {
@@ -160,6 +271,37 @@ impl<Switch: InputSwitch, Config: DebouncedInputConfig> Control for DebouncedInp
                     DebouncedInputEvent::High
                 }
             }
+            DebouncedInputState::Unknown => {
+                self.state = DebouncedInputState::Settling(input_switch_state, now);
+                DebouncedInputEvent::Low
+            }
+            DebouncedInputState::Settling(settling_state, start) => {
+                if input_switch_state != *settling_state {
+                    self.state = DebouncedInputState::Settling(input_switch_state, now);
+                    DebouncedInputEvent::Low
+                } else if Config::DEBOUNCE_TIMER
+                    .timeout(start, &now)
+                    .map_err(Error::ElapsedTimer)?
+                {
+                    if input_switch_state {
+                        self.state = DebouncedInputState::FixedHigh;
+                        DebouncedInputEvent::High
+                    } else {
+                        self.state = DebouncedInputState::FixedLow;
+                        DebouncedInputEvent::Low
+                    }
+                } else {
+                    DebouncedInputEvent::Low
+                }
+            }
         })
     }
 }
+
+impl<Switch: InputSwitch, Config: DebouncedInputConfig> RawLevels
+    for DebouncedInput<Switch, Config>
+{
+    fn raw_levels(&self) -> u8 {
+        self.raw_level as u8
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{Control, DualChannelInputError};
+
+use core::marker::PhantomData;
+use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`DualChannelInput`](crate::DualChannelInput).
+pub trait DualChannelInputConfig {
+    /// Elapsed timer type that used for [`DualChannelInput`](crate::DualChannelInput).
+    type Timer: ElapsedTimer;
+
+    /// Elapsed timer instance that used for [`DualChannelInput`](crate::DualChannelInput).
+    /// The discrepancy fault is latched if channels disagree until this timer is elapsed.
+    const DISCREPANCY_TIMER: Self::Timer;
+
+    /// The active state of channel A switch that means safe.
+    const SAFE_LEVEL_A: bool = true;
+
+    /// The active state of channel B switch that means safe, e.g. `false` for a NC contact
+    /// if channel A is a NO contact of the same device.
+    const SAFE_LEVEL_B: bool = true;
+}
+
+/// The state machine of [`DualChannelInput`](crate::DualChannelInput).
+pub enum DualChannelInputState<T> {
+    Safe,
+    Tripped,
+    Disagreement(T),
+    PartiallyTripped(T),
+    Fault,
+}
+
+/// Concrete implementation of dual-channel safety input (emergency stop, guard door and etc.).
+///
+/// The input is safe only if both channels are safe, otherwise it's tripped.
+/// Channels of the tripped input must both become safe within
+/// [`DISCREPANCY_TIMER`](crate::DualChannelInputConfig::DISCREPANCY_TIMER).
+/// Once a channel of the safe input trips, the other channel must trip within the timer too,
+/// and the input isn't safe again until both channels are seen tripped.
+/// Otherwise the discrepancy fault is latched until [`reset`](crate::DualChannelInput::reset).
+/// The input is tripped upon creation and upon a read error of any channel.
+///
+/// # Type Params
+/// `SwitchA` - [`InputSwitch`](switch_hal::InputSwitch) that provides input of channel A.
+///
+/// `SwitchB` - [`InputSwitch`](switch_hal::InputSwitch) that provides input of channel B.
+///
+/// `Config` - [`DualChannelInputConfig`](crate::DualChannelInputConfig) that provides configs for dual-channel input.
+///
+/// # Example
+/// ```ignore
+/// dual_channel_input_config!(
+///     SomeDualChannelInputConfig,
+///     discrepancy_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis()),
+///     safe_level_a: true,
+///     safe_level_b: false
+/// );
+///
+/// type MyDualChannelInput<SwitchA, SwitchB> = DualChannelInput<SwitchA, SwitchB, SomeDualChannelInputConfig>;
+///
+/// let mut emergency_stop = MyDualChannelInput::new(
+///     pin_nc.into_active_high_switch(),
+///     pin_no.into_active_high_switch(),
+/// );
+///
+/// loop {
+///     match emergency_stop.update().unwrap() {
+///         DualChannelInputEvent::Safe => machine.allow_run(),
+///         DualChannelInputEvent::Tripped => machine.stop(),
+///         DualChannelInputEvent::Discrepancy => {
+///             machine.stop();
+///             if reset_button_pressed() {
+///                 emergency_stop.reset();
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub struct DualChannelInput<
+    SwitchA: InputSwitch,
+    SwitchB: InputSwitch,
+    Config: DualChannelInputConfig,
+> {
+    input_switch_a: SwitchA,
+    input_switch_b: SwitchB,
+    state: DualChannelInputState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    config: PhantomData<Config>,
+}
+
+/// The event result of update [`DualChannelInput`](crate::DualChannelInput).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DualChannelInputEvent {
+    /// Both channels are safe.
+    Safe,
+    /// Any channel is tripped.
+    Tripped,
+    /// The discrepancy fault is latched, the input is treated as tripped until reset.
+    Discrepancy,
+}
+
+impl<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: DualChannelInputConfig>
+    DualChannelInput<SwitchA, SwitchB, Config>
+{
+    /// Creates a new [`DualChannelInput<SwitchA, SwitchB, Config>`] from concretes `SwitchA`, `SwitchB`.
+    ///
+    /// `input_switch_a` - an concrete instance of `SwitchA`.
+    ///
+    /// `input_switch_b` - an concrete instance of `SwitchB`.
+    pub fn new(input_switch_a: SwitchA, input_switch_b: SwitchB) -> Self {
+        DualChannelInput {
+            input_switch_a,
+            input_switch_b,
+            state: DualChannelInputState::Tripped,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Resets the latched discrepancy fault, channels are checked again upon the next update.
+    pub fn reset(&mut self) {
+        if self.is_fault() {
+            self.state = DualChannelInputState::Tripped;
+        }
+    }
+
+    /// Returns true if both channels are safe.
+    pub fn is_safe(&self) -> bool {
+        matches!(self.state, DualChannelInputState::Safe)
+    }
+
+    /// Returns true if the discrepancy fault is latched.
+    pub fn is_fault(&self) -> bool {
+        matches!(self.state, DualChannelInputState::Fault)
+    }
+
+    /// Borrow `SwitchA`.
+    pub fn borrow_input_switch_a(&self) -> &SwitchA {
+        &self.input_switch_a
+    }
+
+    /// Borrow `SwitchB`.
+    pub fn borrow_input_switch_b(&self) -> &SwitchB {
+        &self.input_switch_b
+    }
+
+    /// Consumes `self` and release `SwitchA`, `SwitchB`.
+    pub fn release_input_switches(self) -> (SwitchA, SwitchB) {
+        (self.input_switch_a, self.input_switch_b)
+    }
+}
+
+impl<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: DualChannelInputConfig> Control
+    for DualChannelInput<SwitchA, SwitchB, Config>
+{
+    type Event = DualChannelInputEvent;
+    type Error = DualChannelInputError<
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+        SwitchA::Error,
+        SwitchB::Error,
+    >;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+        let result_a = self
+            .input_switch_a
+            .is_active()
+            .map_err(DualChannelInputError::InputSwitchA);
+        let result_b = self
+            .input_switch_b
+            .is_active()
+            .map_err(DualChannelInputError::InputSwitchB);
+
+        let (safe_a, safe_b) = match result_a.and_then(|a| result_b.map(|b| (a, b))) {
+            Ok((a, b)) => (a == Config::SAFE_LEVEL_A, b == Config::SAFE_LEVEL_B),
+            Err(error) => {
+                if self.is_safe() {
+                    self.state = DualChannelInputState::Tripped;
+                }
+                return Err(error);
+            }
+        };
+
+        self.state = match &self.state {
+            DualChannelInputState::Fault => return Ok(DualChannelInputEvent::Discrepancy),
+            _ if !safe_a && !safe_b => DualChannelInputState::Tripped,
+            DualChannelInputState::Disagreement(start)
+            | DualChannelInputState::PartiallyTripped(start) => {
+                if Config::DISCREPANCY_TIMER
+                    .timeout(start, &now)
+                    .map_err(DualChannelInputError::ElapsedTimer)?
+                {
+                    DualChannelInputState::Fault
+                } else if safe_a
+                    && safe_b
+                    && matches!(self.state, DualChannelInputState::Disagreement(_))
+                {
+                    DualChannelInputState::Safe
+                } else {
+                    return Ok(DualChannelInputEvent::Tripped);
+                }
+            }
+            _ if safe_a && safe_b => DualChannelInputState::Safe,
+            DualChannelInputState::Safe => DualChannelInputState::PartiallyTripped(now),
+            _ => DualChannelInputState::Disagreement(now),
+        };
+
+        Ok(match self.state {
+            DualChannelInputState::Safe => DualChannelInputEvent::Safe,
+            DualChannelInputState::Fault => DualChannelInputEvent::Discrepancy,
+            _ => DualChannelInputEvent::Tripped,
+        })
+    }
+}
@@ -1,9 +1,12 @@
-use crate::{Control, DebouncedInput, DebouncedInputConfig, DebouncedInputEvent};
+use crate::{
+    Control, DebouncedInput, DebouncedInputConfig, DebouncedInputEvent, EncoderError, RawLevels,
+};
 
 use core::ops::AddAssign;
 use num_integer::Integer;
 use num_traits::{One, Signed, Zero};
 use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
 
 /// Represents a config for [`Encoder`](crate::Encoder).
 pub trait EncoderConfig: DebouncedInputConfig {
@@ -54,6 +57,8 @@ pub struct Encoder<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: EncoderCo
 
 /// The event result of update [`Encoder`](crate::Encoder).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum EncoderEvent {
     /// Encoder doesn't rotate.
     NoTurn,
@@ -75,6 +80,16 @@ impl<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: EncoderConfig>
         }
     }
 
+    /// Creates a new [`Encoder<SwitchA, SwitchB, Config>`] from concretes `SwitchA`, `SwitchB`
+    /// with unknown initial states, see [`DebouncedInput::new_unknown`].
+    pub fn new_unknown(input_switch_a: SwitchA, input_switch_b: SwitchB) -> Self {
+        Encoder {
+            debounced_input_a: DebouncedInput::new_unknown(input_switch_a),
+            debounced_input_b: DebouncedInput::new_unknown(input_switch_b),
+            counts: Zero::zero(),
+        }
+    }
+
     /// Consumses `self` and release `(SwitchA, SwitchB)`.
     pub fn release_input_switches(self) -> (SwitchA, SwitchB) {
         (
@@ -82,20 +97,44 @@ impl<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: EncoderConfig>
             self.debounced_input_b.release_input_switch(),
         )
     }
+
+    /// Creates a new [`Encoder<SwitchA, SwitchB, Config>`] from concretes `SwitchA`, `SwitchB`,
+    /// unlike [`new`](Encoder::new) it reports errors of the initial reads.
+    /// # Errors
+    /// This function will return an error if the initial read of `input_switch_a` or `input_switch_b` is failed.
+    pub fn try_new(
+        input_switch_a: SwitchA,
+        input_switch_b: SwitchB,
+    ) -> Result<Self, <Self as Control>::Error> {
+        Ok(Encoder {
+            debounced_input_a: DebouncedInput::try_new(input_switch_a)
+                .map_err(EncoderError::from_a)?,
+            debounced_input_b: DebouncedInput::try_new(input_switch_b)
+                .map_err(EncoderError::from_b)?,
+            counts: Zero::zero(),
+        })
+    }
 }
 
 impl<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: EncoderConfig> Control
     for Encoder<SwitchA, SwitchB, Config>
-where
-    <DebouncedInput<SwitchA, Config> as Control>::Error:
-        From<<DebouncedInput<SwitchB, Config> as Control>::Error>,
 {
     type Event = EncoderEvent;
-    type Error = <DebouncedInput<SwitchA, Config> as Control>::Error;
+    type Error = EncoderError<
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+        SwitchA::Error,
+        SwitchB::Error,
+    >;
 
     fn update(&mut self) -> Result<Self::Event, Self::Error> {
-        let a_event = self.debounced_input_a.update()?;
-        let b_event = self.debounced_input_b.update()?;
+        let a_event = self
+            .debounced_input_a
+            .update()
+            .map_err(EncoderError::from_a)?;
+        let b_event = self
+            .debounced_input_b
+            .update()
+            .map_err(EncoderError::from_b)?;
 
         fn check_event<Counts: Signed>(
             event: DebouncedInputEvent,
@@ -132,3 +171,11 @@ where
         Ok(result_event)
     }
 }
+
+impl<SwitchA: InputSwitch, SwitchB: InputSwitch, Config: EncoderConfig> RawLevels
+    for Encoder<SwitchA, SwitchB, Config>
+{
+    fn raw_levels(&self) -> u8 {
+        self.debounced_input_a.raw_levels() | self.debounced_input_b.raw_levels() << 1
+    }
+}
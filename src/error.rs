@@ -1,8 +1,257 @@
+use core::fmt::{Debug, Display, Formatter};
+
 /// Controls errors container.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<T, S> {
-    // Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
     ElapsedTimer(T),
-    // Error of [`InputSwitch`](switch_hal::InputSwitch)
+    /// Error of [`InputSwitch`](switch_hal::InputSwitch)
     InputSwitch(S),
 }
+
+/// The kind of [`Error`](crate::Error), e.g. to handle errors without the concrete sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ErrorKind {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer,
+    /// Error of input
+    InputSwitch,
+}
+
+impl<T, S> Error<T, S> {
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::ElapsedTimer(_) => ErrorKind::ElapsedTimer,
+            Error::InputSwitch(_) => ErrorKind::InputSwitch,
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErrorKind::ElapsedTimer => f.write_str("elapsed timer error"),
+            ErrorKind::InputSwitch => f.write_str("input error"),
+        }
+    }
+}
+
+impl<T: Display, S: Display> Display for Error<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::ElapsedTimer(error) => write!(f, "{}: {}", self.kind(), error),
+            Error::InputSwitch(error) => write!(f, "{}: {}", self.kind(), error),
+        }
+    }
+}
+
+impl<T: Debug + Display, S: Debug + Display> core::error::Error for Error<T, S> {}
+
+/// Errors container of [`Encoder`](crate::Encoder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EncoderError<T, A, B> {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer(T),
+    /// Error of channel A [`InputSwitch`](switch_hal::InputSwitch)
+    InputSwitchA(A),
+    /// Error of channel B [`InputSwitch`](switch_hal::InputSwitch)
+    InputSwitchB(B),
+}
+
+impl<T, A, B> EncoderError<T, A, B> {
+    pub(crate) fn from_a(error: Error<T, A>) -> Self {
+        match error {
+            Error::ElapsedTimer(error) => EncoderError::ElapsedTimer(error),
+            Error::InputSwitch(error) => EncoderError::InputSwitchA(error),
+        }
+    }
+
+    pub(crate) fn from_b(error: Error<T, B>) -> Self {
+        match error {
+            Error::ElapsedTimer(error) => EncoderError::ElapsedTimer(error),
+            Error::InputSwitch(error) => EncoderError::InputSwitchB(error),
+        }
+    }
+}
+
+impl<T: Display, A: Display, B: Display> Display for EncoderError<T, A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EncoderError::ElapsedTimer(error) => write!(f, "elapsed timer error: {}", error),
+            EncoderError::InputSwitchA(error) => write!(f, "channel A input error: {}", error),
+            EncoderError::InputSwitchB(error) => write!(f, "channel B input error: {}", error),
+        }
+    }
+}
+
+impl<T: Debug + Display, A: Debug + Display, B: Debug + Display> core::error::Error
+    for EncoderError<T, A, B>
+{
+}
+
+/// Errors container of [`DualChannelInput`](crate::DualChannelInput).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DualChannelInputError<T, A, B> {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer(T),
+    /// Error of channel A [`InputSwitch`](switch_hal::InputSwitch)
+    InputSwitchA(A),
+    /// Error of channel B [`InputSwitch`](switch_hal::InputSwitch)
+    InputSwitchB(B),
+}
+
+impl<T: Display, A: Display, B: Display> Display for DualChannelInputError<T, A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DualChannelInputError::ElapsedTimer(error) => {
+                write!(f, "elapsed timer error: {}", error)
+            }
+            DualChannelInputError::InputSwitchA(error) => {
+                write!(f, "channel A input error: {}", error)
+            }
+            DualChannelInputError::InputSwitchB(error) => {
+                write!(f, "channel B input error: {}", error)
+            }
+        }
+    }
+}
+
+impl<T: Debug + Display, A: Debug + Display, B: Debug + Display> core::error::Error
+    for DualChannelInputError<T, A, B>
+{
+}
+
+/// Errors container of [`Output`](crate::Output), [`Indicator`](crate::Indicator) and [`Beeper`](crate::Beeper).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputError<T, S> {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer(T),
+    /// Error of [`OutputSwitch`](switch_hal::OutputSwitch) or [`ToneOutput`](crate::ToneOutput)
+    OutputSwitch(S),
+}
+
+impl<T: Display, S: Display> Display for OutputError<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OutputError::ElapsedTimer(error) => write!(f, "elapsed timer error: {}", error),
+            OutputError::OutputSwitch(error) => write!(f, "output switch error: {}", error),
+        }
+    }
+}
+
+impl<T: Debug + Display, S: Debug + Display> core::error::Error for OutputError<T, S> {}
+
+/// Errors container of [`AnalogButtons`](crate::AnalogButtons).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalogButtonsError<T, A> {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer(T),
+    /// Error of [`AnalogInput`](crate::AnalogInput)
+    AnalogInput(A),
+}
+
+impl<T: Display, A: Display> Display for AnalogButtonsError<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AnalogButtonsError::ElapsedTimer(error) => write!(f, "elapsed timer error: {}", error),
+            AnalogButtonsError::AnalogInput(error) => write!(f, "analog input error: {}", error),
+        }
+    }
+}
+
+impl<T: Debug + Display, A: Debug + Display> core::error::Error for AnalogButtonsError<T, A> {}
+
+/// Errors container of [`Joystick`](crate::Joystick).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoystickError<T, A, S> {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer(T),
+    /// Error of axis [`AnalogInput`](crate::AnalogInput)
+    Axis(A),
+    /// Error of button [`InputSwitch`](switch_hal::InputSwitch)
+    Button(S),
+}
+
+impl<T, A, S> From<Error<T, S>> for JoystickError<T, A, S> {
+    fn from(error: Error<T, S>) -> Self {
+        match error {
+            Error::ElapsedTimer(error) => JoystickError::ElapsedTimer(error),
+            Error::InputSwitch(error) => JoystickError::Button(error),
+        }
+    }
+}
+
+impl<T: Display, A: Display, S: Display> Display for JoystickError<T, A, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            JoystickError::ElapsedTimer(error) => write!(f, "elapsed timer error: {}", error),
+            JoystickError::Axis(error) => write!(f, "axis error: {}", error),
+            JoystickError::Button(error) => write!(f, "button error: {}", error),
+        }
+    }
+}
+
+impl<T: Debug + Display, A: Debug + Display, S: Debug + Display> core::error::Error
+    for JoystickError<T, A, S>
+{
+}
+
+/// Errors container of [`TouchKey`](crate::TouchKey).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchKeyError<T, O, I> {
+    /// Error of [`ElapsedTimer`](timestamp_source::ElapsedTimer)
+    ElapsedTimer(T),
+    /// Error of [`OutputSwitch`](switch_hal::OutputSwitch)
+    OutputSwitch(O),
+    /// Error of [`InputSwitch`](switch_hal::InputSwitch)
+    InputSwitch(I),
+}
+
+impl<T: Display, O: Display, I: Display> Display for TouchKeyError<T, O, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TouchKeyError::ElapsedTimer(error) => write!(f, "elapsed timer error: {}", error),
+            TouchKeyError::OutputSwitch(error) => write!(f, "output switch error: {}", error),
+            TouchKeyError::InputSwitch(error) => write!(f, "input switch error: {}", error),
+        }
+    }
+}
+
+impl<T: Debug + Display, O: Debug + Display, I: Debug + Display> core::error::Error
+    for TouchKeyError<T, O, I>
+{
+}
+
+/// Errors container of switch combinators, e.g. [`AnyOf`](crate::AnyOf).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombinedSwitchError<A, B> {
+    /// Error of the first [`InputSwitch`](switch_hal::InputSwitch)
+    A(A),
+    /// Error of the second [`InputSwitch`](switch_hal::InputSwitch)
+    B(B),
+    /// Errors of both [`InputSwitch`](switch_hal::InputSwitch)es
+    Both(A, B),
+}
+
+impl<A: Display, B: Display> Display for CombinedSwitchError<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CombinedSwitchError::A(error) => write!(f, "first input switch error: {}", error),
+            CombinedSwitchError::B(error) => write!(f, "second input switch error: {}", error),
+            CombinedSwitchError::Both(error_a, error_b) => write!(
+                f,
+                "first input switch error: {}, second input switch error: {}",
+                error_a, error_b
+            ),
+        }
+    }
+}
+
+impl<A: Debug + Display, B: Debug + Display> core::error::Error for CombinedSwitchError<A, B> {}
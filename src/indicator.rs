@@ -0,0 +1,237 @@
+use crate::{Control, OutputError};
+
+use switch_hal::OutputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// The step of [`IndicatorPattern`](crate::IndicatorPattern), the level is held until the timer is elapsed.
+pub struct IndicatorStep<Timer: 'static> {
+    /// The level of the output during the step.
+    pub on: bool,
+    /// Elapsed timer instance that defines the duration of the step.
+    pub timer: Timer,
+}
+
+/// The pattern of [`Indicator`](crate::Indicator), usually defined as a const table.
+///
+/// # Example
+/// ```ignore
+/// const BLINK: IndicatorPattern<MyElapsedTimer> = IndicatorPattern::Repeat(&[
+///     IndicatorStep::new(true, MyElapsedTimer::new(250.millis())),
+///     IndicatorStep::new(false, MyElapsedTimer::new(750.millis())),
+/// ]);
+///
+/// const ERROR_CODE_3: IndicatorPattern<MyElapsedTimer> = IndicatorPattern::Repeat(&[
+///     IndicatorStep::new(true, MyElapsedTimer::new(200.millis())),
+///     IndicatorStep::new(false, MyElapsedTimer::new(200.millis())),
+///     IndicatorStep::new(true, MyElapsedTimer::new(200.millis())),
+///     IndicatorStep::new(false, MyElapsedTimer::new(200.millis())),
+///     IndicatorStep::new(true, MyElapsedTimer::new(200.millis())),
+///     IndicatorStep::new(false, MyElapsedTimer::new(2.secs())),
+/// ]);
+///
+/// const FLASH: IndicatorPattern<MyElapsedTimer> =
+///     IndicatorPattern::Once(&[IndicatorStep::new(true, MyElapsedTimer::new(50.millis()))]);
+/// ```
+pub enum IndicatorPattern<Timer: 'static> {
+    /// The output is steady on (`true`) or off (`false`).
+    Steady(bool),
+    /// Steps are played repeatedly.
+    Repeat(&'static [IndicatorStep<Timer>]),
+    /// Steps are played once, after that the previous pattern is resumed.
+    Once(&'static [IndicatorStep<Timer>]),
+}
+
+/// Concrete implementation of indicator (LED and etc.) that plays patterns.
+///
+/// If switching the output is failed, the pattern keeps playing and the output is switched again
+/// upon the next update, [`IndicatorEvent::Finished`] is reported once the output is switched.
+///
+/// # Type Params
+/// `Switch` - [`OutputSwitch`](switch_hal::OutputSwitch) that drives the indicator.
+///
+/// `Timer` - [`ElapsedTimer`](timestamp_source::ElapsedTimer) that is used by steps of patterns.
+///
+/// # Example
+/// ```ignore
+/// let mut indicator = Indicator::new(led_pin.into_active_high_switch());
+///
+/// indicator.play(BLINK);
+///
+/// loop {
+///     if button_pressed() {
+///         indicator.play(FLASH);
+///     }
+///
+///     if let Some(code) = error_code() {
+///         indicator.play(ERROR_CODE_3);
+///     }
+///
+///     indicator.update().unwrap();
+/// }
+/// ```
+pub struct Indicator<Switch: OutputSwitch, Timer: ElapsedTimer + 'static> {
+    output_switch: Switch,
+    pattern: IndicatorPattern<Timer>,
+    base_pattern: IndicatorPattern<Timer>,
+    step: usize,
+    step_start: Option<Timer::Timestamp>,
+    driven: Option<bool>,
+    finished: bool,
+}
+
+/// The event result of update [`Indicator`](crate::Indicator).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorEvent {
+    /// The output isn't changed.
+    NoChange,
+    /// The output is switched on (`true`) or off (`false`).
+    Switched(bool),
+    /// The one-shot pattern is finished and the previous pattern is resumed.
+    Finished,
+}
+
+impl<Timer> IndicatorStep<Timer> {
+    /// Creates a new [`IndicatorStep<Timer>`].
+    pub const fn new(on: bool, timer: Timer) -> Self {
+        IndicatorStep { on, timer }
+    }
+}
+
+impl<Timer> IndicatorPattern<Timer> {
+    /// Returns steps of the pattern, steady pattern has no steps.
+    pub fn steps(&self) -> &'static [IndicatorStep<Timer>] {
+        match self {
+            IndicatorPattern::Steady(_) => &[],
+            IndicatorPattern::Repeat(steps) | IndicatorPattern::Once(steps) => steps,
+        }
+    }
+
+    fn level(&self, step: usize) -> bool {
+        match self {
+            IndicatorPattern::Steady(on) => *on,
+            _ => self.steps().get(step).is_some_and(|step| step.on),
+        }
+    }
+}
+
+impl<Timer> Clone for IndicatorPattern<Timer> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Timer> Copy for IndicatorPattern<Timer> {}
+
+impl<Switch: OutputSwitch, Timer: ElapsedTimer + 'static> Indicator<Switch, Timer> {
+    /// Creates a new [`Indicator<Switch, Timer>`] from a concrete `Switch`, the initial pattern is steady off.
+    ///
+    /// `output_switch` - an concrete instance of `Switch`.
+    pub fn new(output_switch: Switch) -> Self {
+        Indicator {
+            output_switch,
+            pattern: IndicatorPattern::Steady(false),
+            base_pattern: IndicatorPattern::Steady(false),
+            step: 0,
+            step_start: None,
+            driven: None,
+            finished: false,
+        }
+    }
+
+    /// Plays `pattern` from the first step upon the next update.
+    ///
+    /// [`IndicatorPattern::Once`] is played over the current pattern, other patterns replace it.
+    /// An empty [`IndicatorPattern::Once`] is ignored.
+    pub fn play(&mut self, pattern: IndicatorPattern<Timer>) {
+        if matches!(pattern, IndicatorPattern::Once(steps) if steps.is_empty()) {
+            return;
+        }
+
+        if !matches!(pattern, IndicatorPattern::Once(_)) {
+            self.base_pattern = pattern;
+        }
+
+        self.pattern = pattern;
+        self.step = 0;
+        self.step_start = None;
+        self.finished = false;
+    }
+
+    /// Returns true if a one-shot pattern is playing.
+    pub fn is_playing_once(&self) -> bool {
+        matches!(self.pattern, IndicatorPattern::Once(_))
+    }
+
+    /// Returns the is switched on state.
+    pub fn is_on(&self) -> bool {
+        self.driven == Some(true)
+    }
+
+    /// Borrow `Switch`.
+    pub fn borrow_output_switch(&self) -> &Switch {
+        &self.output_switch
+    }
+
+    /// Consumes `self` and release `Switch`.
+    pub fn release_output_switch(self) -> Switch {
+        self.output_switch
+    }
+}
+
+impl<Switch: OutputSwitch, Timer: ElapsedTimer + 'static> Control for Indicator<Switch, Timer> {
+    type Event = IndicatorEvent;
+    type Error = OutputError<<Timer::Timestamp as Timestamp>::Error, Switch::Error>;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let now = Timer::Timestamp::now();
+
+        match &self.step_start {
+            None => {
+                self.step = 0;
+                self.step_start = Some(now);
+            }
+            Some(step_start) => {
+                let steps = self.pattern.steps();
+
+                if let Some(step) = steps.get(self.step) {
+                    if step
+                        .timer
+                        .timeout(step_start, &now)
+                        .map_err(OutputError::ElapsedTimer)?
+                    {
+                        self.step += 1;
+                        if self.step >= steps.len() {
+                            self.step = 0;
+
+                            if self.is_playing_once() {
+                                self.pattern = self.base_pattern;
+                                self.finished = true;
+                            }
+                        }
+                        self.step_start = Some(now);
+                    }
+                }
+            }
+        }
+
+        let level = self.pattern.level(self.step);
+        let switched = self.driven != Some(level);
+
+        if switched {
+            match level {
+                true => self.output_switch.on(),
+                false => self.output_switch.off(),
+            }
+            .map_err(OutputError::OutputSwitch)?;
+            self.driven = Some(level);
+        }
+
+        Ok(if core::mem::take(&mut self.finished) {
+            IndicatorEvent::Finished
+        } else if switched {
+            IndicatorEvent::Switched(level)
+        } else {
+            IndicatorEvent::NoChange
+        })
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{AnalogInput, Control, DebouncedInput, DebouncedInputConfig, JoystickError};
+
+use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// The scale of one axis of [`Joystick`](crate::Joystick), the position is `raw * multiplier / divisor`.
+/// Negative `multiplier` inverts an axis, `divisor` must be positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JoystickAxisScale {
+    pub multiplier: i32,
+    pub divisor: i32,
+}
+
+/// The number of directions of [`Joystick`](crate::Joystick).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoystickDirections {
+    Four,
+    Eight,
+}
+
+/// The direction of [`Joystick`](crate::Joystick), the positive Y axis is up, the positive X axis is right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoystickDirection {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+/// Represents a config for [`Joystick`](crate::Joystick).
+pub trait JoystickConfig: DebouncedInputConfig {
+    /// The scale of X axis.
+    const X_SCALE: JoystickAxisScale;
+
+    /// The scale of Y axis.
+    const Y_SCALE: JoystickAxisScale;
+
+    /// The radius of dead zone around the center in scaled units.
+    const DEAD_ZONE: u16;
+
+    /// The radius that position must pass beyond the dead zone to leave the center.
+    const DEAD_ZONE_HYSTERESIS: u16;
+
+    /// The number of directions.
+    const DIRECTIONS: JoystickDirections;
+
+    /// The percent that a new direction must outweigh the current one to change it.
+    const DIRECTION_HYSTERESIS: u16;
+
+    /// Elapsed timer instance that used for [`Joystick`](crate::Joystick).
+    /// The first repeat of a held direction is reported after this timer is elapsed.
+    const REPEAT_DELAY_TIMER: Self::Timer;
+
+    /// Elapsed timer instance that used for [`Joystick`](crate::Joystick).
+    /// Next repeats of a held direction are reported every time this timer is elapsed.
+    const REPEAT_INTERVAL_TIMER: Self::Timer;
+}
+
+/// The state machine of [`Joystick`](crate::Joystick).
+pub enum JoystickState<T> {
+    Center,
+    Held(JoystickDirection, T),
+    Repeating(JoystickDirection, T),
+}
+
+/// Concrete implementation of two-axis analog joystick with a push button.
+///
+/// One event is reported per update, button events are reported before direction events.
+/// A change from one direction to another is reported as [`JoystickEvent::Released`]
+/// of the old direction followed by [`JoystickEvent::Pressed`] of the new one upon the next update.
+///
+/// # Type Params
+/// `Axis` - [`AnalogInput`](crate::AnalogInput) that provides raw values of X and Y axes.
+///
+/// `Switch` - [`InputSwitch`](switch_hal::InputSwitch) that provides input of the push button.
+///
+/// `Config` - [`JoystickConfig`](crate::JoystickConfig) that provides configs for joystick.
+///
+/// # Example
+/// ```ignore
+/// joystick_config!(
+///     SomeJoystickConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     x_scale: JoystickAxisScale { multiplier: 1, divisor: 1 },
+///     y_scale: JoystickAxisScale { multiplier: -1, divisor: 1 },
+///     dead_zone: 400,
+///     dead_zone_hysteresis: 100,
+///     directions: JoystickDirections::Eight,
+///     direction_hysteresis: 20,
+///     repeat_delay_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis()),
+///     repeat_interval_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis())
+/// );
+///
+/// type MyJoystick<Axis, Switch> = Joystick<Axis, Switch, SomeJoystickConfig>;
+///
+/// let mut joystick = MyJoystick::new(adc_x, adc_y, pin.into_active_low_switch());
+///
+/// loop {
+///     match joystick.update().unwrap() {
+///         JoystickEvent::Pressed(direction) | JoystickEvent::Repeated(direction) => {
+///             do_something_upon_direction(direction)
+///         }
+///         JoystickEvent::ButtonPressed => do_something_upon_button_press(),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct Joystick<Axis: AnalogInput, Switch: InputSwitch, Config: JoystickConfig> {
+    axis_x: Axis,
+    axis_y: Axis,
+    center: (u16, u16),
+    position: (i32, i32),
+    debounced_input: DebouncedInput<Switch, Config>,
+    state: JoystickState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    reported_direction: Option<JoystickDirection>,
+    reported_button: bool,
+}
+
+/// The event result of update [`Joystick`](crate::Joystick).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoystickEvent {
+    /// No direction or button event.
+    NoEvent,
+    /// The joystick is moved to the direction.
+    Pressed(JoystickDirection),
+    /// The direction is still held, auto-repeat.
+    Repeated(JoystickDirection),
+    /// The joystick is returned from the direction to the center.
+    Released(JoystickDirection),
+    /// The push button is pressed.
+    ButtonPressed,
+    /// The push button is released.
+    ButtonReleased,
+}
+
+impl JoystickDirection {
+    const ALL: [JoystickDirection; 8] = [
+        JoystickDirection::Up,
+        JoystickDirection::UpRight,
+        JoystickDirection::Right,
+        JoystickDirection::DownRight,
+        JoystickDirection::Down,
+        JoystickDirection::DownLeft,
+        JoystickDirection::Left,
+        JoystickDirection::UpLeft,
+    ];
+
+    /// Returns true if the direction is diagonal.
+    pub fn is_diagonal(self) -> bool {
+        matches!(
+            self,
+            JoystickDirection::UpRight
+                | JoystickDirection::DownRight
+                | JoystickDirection::DownLeft
+                | JoystickDirection::UpLeft
+        )
+    }
+
+    /// Returns the unit vector of the direction scaled by 1000.
+    fn unit(self) -> (i64, i64) {
+        const D: i64 = 707;
+        match self {
+            JoystickDirection::Up => (0, 1000),
+            JoystickDirection::UpRight => (D, D),
+            JoystickDirection::Right => (1000, 0),
+            JoystickDirection::DownRight => (D, -D),
+            JoystickDirection::Down => (0, -1000),
+            JoystickDirection::DownLeft => (-D, -D),
+            JoystickDirection::Left => (-1000, 0),
+            JoystickDirection::UpLeft => (-D, D),
+        }
+    }
+
+    fn score(self, (x, y): (i32, i32)) -> i64 {
+        let (unit_x, unit_y) = self.unit();
+        x as i64 * unit_x + y as i64 * unit_y
+    }
+}
+
+impl<Axis: AnalogInput, Switch: InputSwitch, Config: JoystickConfig>
+    Joystick<Axis, Switch, Config>
+{
+    /// Creates a new [`Joystick<Axis, Switch, Config>`] from concretes `Axis`es and `Switch`,
+    /// the current position of axes is used as the center, failed reads are treated as `0`.
+    ///
+    /// `axis_x` - an concrete instance of `Axis` for X axis.
+    ///
+    /// `axis_y` - an concrete instance of `Axis` for Y axis.
+    ///
+    /// `input_switch` - an concrete instance of `Switch` for the push button.
+    pub fn new(axis_x: Axis, axis_y: Axis, input_switch: Switch) -> Self {
+        const { assert!(Config::X_SCALE.divisor > 0 && Config::Y_SCALE.divisor > 0) };
+
+        let center = (axis_x.read().unwrap_or(0), axis_y.read().unwrap_or(0));
+        let debounced_input = DebouncedInput::new(input_switch);
+        let reported_button = debounced_input.is_high();
+
+        Joystick {
+            axis_x,
+            axis_y,
+            center,
+            position: (0, 0),
+            debounced_input,
+            state: JoystickState::Center,
+            reported_direction: None,
+            reported_button,
+        }
+    }
+
+    /// Calibrates the center by the current position of axes.
+    pub fn calibrate(&mut self) -> Result<(), Axis::Error> {
+        self.center = (self.axis_x.read()?, self.axis_y.read()?);
+        Ok(())
+    }
+
+    /// Sets the raw values of the center.
+    pub fn set_center(&mut self, center_x: u16, center_y: u16) {
+        self.center = (center_x, center_y);
+    }
+
+    /// Returns the raw values of the center.
+    pub fn center(&self) -> (u16, u16) {
+        self.center
+    }
+
+    /// Returns the last scaled position relative to the center.
+    pub fn position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    /// Returns the held direction, `None` if the joystick is in the center.
+    pub fn direction(&self) -> Option<JoystickDirection> {
+        match self.state {
+            JoystickState::Center => None,
+            JoystickState::Held(direction, _) | JoystickState::Repeating(direction, _) => {
+                Some(direction)
+            }
+        }
+    }
+
+    /// Returns the is stable pressed state of the push button.
+    pub fn is_button_pressed(&self) -> bool {
+        self.debounced_input.is_high()
+    }
+
+    /// Consumes `self` and release `(Axis, Axis, Switch)`.
+    pub fn release(self) -> (Axis, Axis, Switch) {
+        (
+            self.axis_x,
+            self.axis_y,
+            self.debounced_input.release_input_switch(),
+        )
+    }
+
+    fn scale(raw: u16, center: u16, scale: JoystickAxisScale) -> i32 {
+        let position =
+            (raw as i64 - center as i64) * scale.multiplier as i64 / scale.divisor as i64;
+        position.clamp(-(i32::MAX as i64), i32::MAX as i64) as i32
+    }
+
+    fn quantize(&self) -> Option<JoystickDirection> {
+        let (x, y) = self.position;
+        let radius_square = x as i64 * x as i64 + y as i64 * y as i64;
+        let current = self.direction();
+
+        let dead_zone = Config::DEAD_ZONE as i64;
+        let dead_zone = match current {
+            Some(_) => dead_zone,
+            None => dead_zone + Config::DEAD_ZONE_HYSTERESIS as i64,
+        };
+
+        if radius_square <= dead_zone * dead_zone {
+            return None;
+        }
+
+        let best = JoystickDirection::ALL
+            .into_iter()
+            .filter(|direction| {
+                Config::DIRECTIONS == JoystickDirections::Eight || !direction.is_diagonal()
+            })
+            .max_by_key(|direction| direction.score(self.position))?;
+
+        match current {
+            Some(current)
+                if current != best
+                    && best.score(self.position) * 100
+                        <= current.score(self.position)
+                            * (100 + Config::DIRECTION_HYSTERESIS as i64) =>
+            {
+                Some(current)
+            }
+            _ => Some(best),
+        }
+    }
+}
+
+impl<Axis: AnalogInput, Switch: InputSwitch, Config: JoystickConfig> Control
+    for Joystick<Axis, Switch, Config>
+{
+    type Event = JoystickEvent;
+    type Error = JoystickError<
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+        Axis::Error,
+        Switch::Error,
+    >;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        self.debounced_input.update()?;
+        let raw_x = self.axis_x.read().map_err(JoystickError::Axis)?;
+        let raw_y = self.axis_y.read().map_err(JoystickError::Axis)?;
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        self.position = (
+            Self::scale(raw_x, self.center.0, Config::X_SCALE),
+            Self::scale(raw_y, self.center.1, Config::Y_SCALE),
+        );
+
+        let direction = self.quantize();
+        let repeated = match (&self.state, direction) {
+            (JoystickState::Held(held_direction, start), Some(direction))
+                if *held_direction == direction =>
+            {
+                let repeated = Config::REPEAT_DELAY_TIMER
+                    .timeout(start, &now)
+                    .map_err(JoystickError::ElapsedTimer)?;
+                if repeated {
+                    self.state = JoystickState::Repeating(direction, now);
+                }
+                repeated
+            }
+            (JoystickState::Repeating(held_direction, start), Some(direction))
+                if *held_direction == direction =>
+            {
+                let repeated = Config::REPEAT_INTERVAL_TIMER
+                    .timeout(start, &now)
+                    .map_err(JoystickError::ElapsedTimer)?;
+                if repeated {
+                    self.state = JoystickState::Repeating(direction, now);
+                }
+                repeated
+            }
+            (JoystickState::Center, None) => false,
+            (_, None) => {
+                self.state = JoystickState::Center;
+                false
+            }
+            (_, Some(direction)) => {
+                self.state = JoystickState::Held(direction, now);
+                false
+            }
+        };
+
+        let button = self.debounced_input.is_high();
+        if button != self.reported_button {
+            self.reported_button = button;
+            return Ok(if button {
+                JoystickEvent::ButtonPressed
+            } else {
+                JoystickEvent::ButtonReleased
+            });
+        }
+
+        Ok(match (self.reported_direction, self.direction()) {
+            (Some(reported), Some(direction)) if reported == direction => {
+                if repeated {
+                    JoystickEvent::Repeated(direction)
+                } else {
+                    JoystickEvent::NoEvent
+                }
+            }
+            (Some(reported), _) => {
+                self.reported_direction = None;
+                JoystickEvent::Released(reported)
+            }
+            (None, Some(direction)) => {
+                self.reported_direction = Some(direction);
+                JoystickEvent::Pressed(direction)
+            }
+            (None, None) => JoystickEvent::NoEvent,
+        })
+    }
+}
@@ -1,18 +1,75 @@
 //! Embedded controls library is based on [switch-hal](https://crates.io/crates/switch-hal)
 //! that allows to handle primitive controls like [`DebounceInput`](crate::DebouncedInput),
-//! [`Encoder`](crate::Encoder).
+//! [`Encoder`](crate::Encoder), [`AbsoluteEncoder`](crate::AbsoluteEncoder).
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod absolute_encoder;
+mod analog_buttons;
+mod analog_switch;
+mod beeper;
+mod chord;
 mod debounced_input;
+mod dual_channel_input;
 mod encoder;
 mod error;
+mod indicator;
+mod joystick;
+mod menu;
+mod output;
+mod pattern_decoder;
+mod potentiometer;
+mod recorder;
+mod sequence_matcher;
+mod switch_combinators;
+mod touch_key;
+mod two_hand_control;
 
 pub mod macros;
+#[cfg(feature = "replay")]
+pub mod replay;
+#[cfg(feature = "simulation")]
+pub mod simulation;
+#[cfg(feature = "testing")]
+pub mod testing;
 
-pub use debounced_input::{DebouncedInput, DebouncedInputConfig, DebouncedInputEvent};
+pub use absolute_encoder::{
+    AbsoluteEncoder, AbsoluteEncoderCoding, AbsoluteEncoderConfig, AbsoluteEncoderEvent,
+};
+pub use analog_buttons::{AnalogButtons, AnalogButtonsConfig, AnalogButtonsEvent};
+pub use analog_switch::AnalogSwitch;
+pub use beeper::{BeepPattern, BeepStep, Beeper, BeeperEvent, SwitchTone};
+pub use chord::{Chord, ChordConfig, ChordEvent};
+pub use debounced_input::{
+    DebouncedInput, DebouncedInputConfig, DebouncedInputEvent, DebouncedInputState,
+};
+pub use dual_channel_input::{DualChannelInput, DualChannelInputConfig, DualChannelInputEvent};
 pub use encoder::{Encoder, EncoderConfig, EncoderEvent};
-pub use error::Error;
+pub use error::{
+    AnalogButtonsError, CombinedSwitchError, DualChannelInputError, EncoderError, Error, ErrorKind,
+    JoystickError, OutputError, TouchKeyError,
+};
+pub use indicator::{Indicator, IndicatorEvent, IndicatorPattern, IndicatorStep};
+pub use joystick::{
+    Joystick, JoystickAxisScale, JoystickConfig, JoystickDirection, JoystickDirections,
+    JoystickEvent,
+};
+pub use menu::{Menu, MenuEvent, MenuInput, MenuItem, MenuItemKind, MenuValue, MenuView};
+pub use output::{Output, OutputConfig, OutputEvent};
+pub use pattern_decoder::{
+    PatternDecoder, PatternDecoderConfig, PatternDecoderEvent, PressPattern,
+};
+pub use potentiometer::{
+    Potentiometer, PotentiometerConfig, PotentiometerEvent, PotentiometerSmoothing,
+};
+pub use recorder::{decode_record_entries, EventCode, RawLevels, RecordEntry, Recorder};
+pub use sequence_matcher::{SequenceMatcher, SequenceMatcherConfig, SequenceMatcherEvent};
+pub use switch_combinators::{AllOf, AnyOf, Inverted, Xor};
+pub use touch_key::{TouchKey, TouchKeyConfig};
+pub use two_hand_control::{TwoHandControl, TwoHandControlConfig, TwoHandControlEvent};
 
 /// Represents a control, such as debounced input, button, encoder and etc.
 pub trait Control {
@@ -22,3 +79,19 @@ pub trait Control {
     /// Update a control and return an current event or error after update.
     fn update(&mut self) -> Result<Self::Event, Self::Error>;
 }
+
+/// Represents an analog input, such as an ADC channel.
+pub trait AnalogInput {
+    type Error;
+
+    /// Read a current raw value of an analog input.
+    fn read(&self) -> Result<u16, Self::Error>;
+}
+
+/// Represents a tone output, such as a PWM channel that drives a buzzer.
+pub trait ToneOutput {
+    type Error;
+
+    /// Play a tone of `frequency` in Hz, `None` means silence.
+    fn set_tone(&mut self, frequency: Option<u32>) -> Result<(), Self::Error>;
+}
@@ -31,20 +31,41 @@
 ///
 /// type MyDebouncedInput<InputSwitch> = DebouncedInput<InputSwitch, SomeDebouncedInputConfig>;
 /// ```
+///
+/// # Example 4
+/// ```ignore
+/// debounced_input_config!(
+///     SomeDebouncedInputConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     max_read_errors: 3
+/// );
+///
+/// type MyDebouncedInput<InputSwitch> = DebouncedInput<InputSwitch, SomeDebouncedInputConfig>;
+/// ```
 #[macro_export]
 macro_rules! debounced_input_config {
-    (impl $config_name:ty, debounce_timer: $timer_type:ty = $timer_value:expr) => {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr
+        $(, max_read_errors: $max_read_errors:expr)?
+    ) => {
         impl $crate::DebouncedInputConfig for $config_name {
             type Timer = $timer_type;
             const DEBOUNCE_TIMER: $timer_type = $timer_value;
+            $(const MAX_READ_ERRORS: u8 = $max_read_errors;)?
         }
     };
-    ($vis:vis $config_name:ident, debounce_timer: $timer_type:ty = $timer_value:expr) => {
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr
+        $(, max_read_errors: $max_read_errors:expr)?
+    ) => {
         $vis struct $config_name;
 
         debounced_input_config!(
             impl $config_name,
             debounce_timer: $timer_type = $timer_value
+            $(, max_read_errors: $max_read_errors)?
         );
     };
 }
@@ -115,3 +136,825 @@ macro_rules! encoder_config {
         );
     };
 }
+
+/// Create a config for [`AbsoluteEncoder`](crate::AbsoluteEncoder).
+///
+/// # Example 1
+/// ```ignore
+/// absolute_encoder_config!(
+///     SomeAbsoluteEncoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(10.millis()),
+///     coding: AbsoluteEncoderCoding::Gray
+/// );
+///
+/// type MyAbsoluteEncoder<Switch> = AbsoluteEncoder<Switch, 4, SomeAbsoluteEncoderConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// absolute_encoder_config!(
+///     pub SomeAbsoluteEncoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(10.millis()),
+///     coding: AbsoluteEncoderCoding::Gray
+/// );
+///
+/// type MyAbsoluteEncoder<Switch> = AbsoluteEncoder<Switch, 4, SomeAbsoluteEncoderConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeAbsoluteEncoderConfig;
+///
+/// absolute_encoder_config!(
+///     impl SomeAbsoluteEncoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(10.millis()),
+///     coding: AbsoluteEncoderCoding::Gray
+/// );
+///
+/// type MyAbsoluteEncoder<Switch> = AbsoluteEncoder<Switch, 4, SomeAbsoluteEncoderConfig>;
+/// ```
+#[macro_export]
+macro_rules! absolute_encoder_config {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        coding: $coding:expr
+    ) => {
+        $crate::debounced_input_config!(
+            impl $config_name,
+            debounce_timer: $timer_type = $timer_value
+        );
+
+        impl $crate::AbsoluteEncoderConfig for $config_name {
+            const CODING: $crate::AbsoluteEncoderCoding = $coding;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        coding: $coding:expr
+    ) => {
+        $vis struct $config_name;
+
+        absolute_encoder_config!(impl $config_name,
+            debounce_timer: $timer_type = $timer_value,
+            coding: $coding
+        );
+    };
+}
+
+/// Create a config for [`Chord`](crate::Chord).
+///
+/// # Example 1
+/// ```ignore
+/// chord_config!(
+///     SomeChordConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(200.millis())
+/// );
+///
+/// type MyChord<Switch> = Chord<Switch, 3, SomeChordConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// chord_config!(
+///     pub SomeChordConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(200.millis())
+/// );
+///
+/// type MyChord<Switch> = Chord<Switch, 3, SomeChordConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeChordConfig;
+///
+/// chord_config!(
+///     impl SomeChordConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(200.millis())
+/// );
+///
+/// type MyChord<Switch> = Chord<Switch, 3, SomeChordConfig>;
+/// ```
+#[macro_export]
+macro_rules! chord_config {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        simultaneity_timer: $simultaneity_timer_type:ty = $simultaneity_timer_value:expr
+    ) => {
+        $crate::debounced_input_config!(
+            impl $config_name,
+            debounce_timer: $timer_type = $timer_value
+        );
+
+        impl $crate::ChordConfig for $config_name {
+            const SIMULTANEITY_TIMER: $simultaneity_timer_type = $simultaneity_timer_value;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        simultaneity_timer: $simultaneity_timer_type:ty = $simultaneity_timer_value:expr
+    ) => {
+        $vis struct $config_name;
+
+        chord_config!(impl $config_name,
+            debounce_timer: $timer_type = $timer_value,
+            simultaneity_timer: $simultaneity_timer_type = $simultaneity_timer_value
+        );
+    };
+}
+
+/// Create a config for [`SequenceMatcher`](crate::SequenceMatcher).
+///
+/// # Example 1
+/// ```ignore
+/// sequence_matcher_config!(
+///     SomeSequenceMatcherConfig,
+///     event: Key,
+///     timeout_timer: MyElapsedTimer = MyElapsedTimer::new(2.secs()),
+///     patterns: &[&[Key::Up, Key::Up, Key::Down, Key::Down, Key::Ok]]
+/// );
+///
+/// type MySequenceMatcher = SequenceMatcher<SomeSequenceMatcherConfig, 5>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// sequence_matcher_config!(
+///     pub SomeSequenceMatcherConfig,
+///     event: EncoderEvent,
+///     timeout_timer: MyElapsedTimer = MyElapsedTimer::new(2.secs()),
+///     patterns: &[&[EncoderEvent::ClockwiseTurn, EncoderEvent::CounterClockwiseTurn]],
+///     idle_events: &[EncoderEvent::NoTurn]
+/// );
+///
+/// type MySequenceMatcher = SequenceMatcher<SomeSequenceMatcherConfig, 2>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeSequenceMatcherConfig;
+///
+/// sequence_matcher_config!(
+///     impl SomeSequenceMatcherConfig,
+///     event: Key,
+///     timeout_timer: MyElapsedTimer = MyElapsedTimer::new(2.secs()),
+///     patterns: &[&[Key::Up, Key::Up, Key::Down, Key::Down, Key::Ok]]
+/// );
+///
+/// type MySequenceMatcher = SequenceMatcher<SomeSequenceMatcherConfig, 5>;
+/// ```
+#[macro_export]
+macro_rules! sequence_matcher_config {
+    (
+        impl $config_name:ty,
+        event: $event_type:ty,
+        timeout_timer: $timer_type:ty = $timer_value:expr,
+        patterns: $patterns:expr
+        $(, idle_events: $idle_events:expr)?
+    ) => {
+        impl $crate::SequenceMatcherConfig for $config_name {
+            type Event = $event_type;
+            type Timer = $timer_type;
+            const TIMEOUT_TIMER: $timer_type = $timer_value;
+            const PATTERNS: &'static [&'static [$event_type]] = $patterns;
+            $(const IDLE_EVENTS: &'static [$event_type] = $idle_events;)?
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        event: $event_type:ty,
+        timeout_timer: $timer_type:ty = $timer_value:expr,
+        patterns: $patterns:expr
+        $(, idle_events: $idle_events:expr)?
+    ) => {
+        $vis struct $config_name;
+
+        sequence_matcher_config!(impl $config_name,
+            event: $event_type,
+            timeout_timer: $timer_type = $timer_value,
+            patterns: $patterns
+            $(, idle_events: $idle_events)?
+        );
+    };
+}
+
+/// Create a config for [`PatternDecoder`](crate::PatternDecoder).
+///
+/// # Example 1
+/// ```ignore
+/// pattern_decoder_config!(
+///     SomePatternDecoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     long_press_timer: MyElapsedTimer = MyElapsedTimer::new(300.millis()),
+///     gap_timer: MyElapsedTimer = MyElapsedTimer::new(800.millis())
+/// );
+///
+/// type MyPatternDecoder<Switch> = PatternDecoder<Switch, SomePatternDecoderConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// pattern_decoder_config!(
+///     pub SomePatternDecoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     long_press_timer: MyElapsedTimer = MyElapsedTimer::new(300.millis()),
+///     gap_timer: MyElapsedTimer = MyElapsedTimer::new(800.millis())
+/// );
+///
+/// type MyPatternDecoder<Switch> = PatternDecoder<Switch, SomePatternDecoderConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomePatternDecoderConfig;
+///
+/// pattern_decoder_config!(
+///     impl SomePatternDecoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     long_press_timer: MyElapsedTimer = MyElapsedTimer::new(300.millis()),
+///     gap_timer: MyElapsedTimer = MyElapsedTimer::new(800.millis())
+/// );
+///
+/// type MyPatternDecoder<Switch> = PatternDecoder<Switch, SomePatternDecoderConfig>;
+/// ```
+#[macro_export]
+macro_rules! pattern_decoder_config {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        long_press_timer: $long_press_timer_type:ty = $long_press_timer_value:expr,
+        gap_timer: $gap_timer_type:ty = $gap_timer_value:expr
+    ) => {
+        $crate::debounced_input_config!(
+            impl $config_name,
+            debounce_timer: $timer_type = $timer_value
+        );
+
+        impl $crate::PatternDecoderConfig for $config_name {
+            const LONG_PRESS_TIMER: $long_press_timer_type = $long_press_timer_value;
+            const GAP_TIMER: $gap_timer_type = $gap_timer_value;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        long_press_timer: $long_press_timer_type:ty = $long_press_timer_value:expr,
+        gap_timer: $gap_timer_type:ty = $gap_timer_value:expr
+    ) => {
+        $vis struct $config_name;
+
+        pattern_decoder_config!(impl $config_name,
+            debounce_timer: $timer_type = $timer_value,
+            long_press_timer: $long_press_timer_type = $long_press_timer_value,
+            gap_timer: $gap_timer_type = $gap_timer_value
+        );
+    };
+}
+
+/// Create a config for [`Potentiometer`](crate::Potentiometer).
+///
+/// # Example 1
+/// ```ignore
+/// potentiometer_config!(
+///     SomePotentiometerConfig,
+///     max_value: 4095,
+///     positions: 100,
+///     smoothing: PotentiometerSmoothing::Exponential(3),
+///     dead_band: 40,
+///     hysteresis: 16
+/// );
+///
+/// type MyPotentiometer<Input> = Potentiometer<Input, SomePotentiometerConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// potentiometer_config!(
+///     pub SomePotentiometerConfig,
+///     max_value: 4095,
+///     positions: 100,
+///     smoothing: PotentiometerSmoothing::Exponential(3),
+///     dead_band: 40,
+///     hysteresis: 16
+/// );
+///
+/// type MyPotentiometer<Input> = Potentiometer<Input, SomePotentiometerConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomePotentiometerConfig;
+///
+/// potentiometer_config!(
+///     impl SomePotentiometerConfig,
+///     max_value: 4095,
+///     positions: 100,
+///     smoothing: PotentiometerSmoothing::Exponential(3),
+///     dead_band: 40,
+///     hysteresis: 16
+/// );
+///
+/// type MyPotentiometer<Input> = Potentiometer<Input, SomePotentiometerConfig>;
+/// ```
+#[macro_export]
+macro_rules! potentiometer_config {
+    (
+        impl $config_name:ty,
+        max_value: $max_value:expr,
+        positions: $positions:expr,
+        smoothing: $smoothing:expr,
+        dead_band: $dead_band:expr,
+        hysteresis: $hysteresis:expr
+    ) => {
+        impl $crate::PotentiometerConfig for $config_name {
+            const MAX_VALUE: u16 = $max_value;
+            const POSITIONS: u16 = $positions;
+            const SMOOTHING: $crate::PotentiometerSmoothing = $smoothing;
+            const DEAD_BAND: u16 = $dead_band;
+            const HYSTERESIS: u16 = $hysteresis;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        max_value: $max_value:expr,
+        positions: $positions:expr,
+        smoothing: $smoothing:expr,
+        dead_band: $dead_band:expr,
+        hysteresis: $hysteresis:expr
+    ) => {
+        $vis struct $config_name;
+
+        potentiometer_config!(impl $config_name,
+            max_value: $max_value,
+            positions: $positions,
+            smoothing: $smoothing,
+            dead_band: $dead_band,
+            hysteresis: $hysteresis
+        );
+    };
+}
+
+/// Create a config for [`AnalogButtons`](crate::AnalogButtons).
+///
+/// # Example 1
+/// ```ignore
+/// analog_buttons_config!(
+///     SomeAnalogButtonsConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     thresholds: &[(0, 100), (600, 800), (1500, 1800)]
+/// );
+///
+/// type MyAnalogButtons<Input> = AnalogButtons<Input, SomeAnalogButtonsConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// analog_buttons_config!(
+///     pub SomeAnalogButtonsConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     thresholds: &[(0, 100), (600, 800), (1500, 1800)]
+/// );
+///
+/// type MyAnalogButtons<Input> = AnalogButtons<Input, SomeAnalogButtonsConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeAnalogButtonsConfig;
+///
+/// analog_buttons_config!(
+///     impl SomeAnalogButtonsConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     thresholds: &[(0, 100), (600, 800), (1500, 1800)]
+/// );
+///
+/// type MyAnalogButtons<Input> = AnalogButtons<Input, SomeAnalogButtonsConfig>;
+/// ```
+#[macro_export]
+macro_rules! analog_buttons_config {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        thresholds: $thresholds:expr
+    ) => {
+        $crate::debounced_input_config!(
+            impl $config_name,
+            debounce_timer: $timer_type = $timer_value
+        );
+
+        impl $crate::AnalogButtonsConfig for $config_name {
+            const THRESHOLDS: &'static [(u16, u16)] = $thresholds;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        thresholds: $thresholds:expr
+    ) => {
+        $vis struct $config_name;
+
+        analog_buttons_config!(impl $config_name,
+            debounce_timer: $timer_type = $timer_value,
+            thresholds: $thresholds
+        );
+    };
+}
+
+/// Create a config for [`Joystick`](crate::Joystick).
+///
+/// # Example 1
+/// ```ignore
+/// joystick_config!(
+///     SomeJoystickConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     x_scale: JoystickAxisScale { multiplier: 1, divisor: 1 },
+///     y_scale: JoystickAxisScale { multiplier: -1, divisor: 1 },
+///     dead_zone: 400,
+///     dead_zone_hysteresis: 100,
+///     directions: JoystickDirections::Eight,
+///     direction_hysteresis: 20,
+///     repeat_delay_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis()),
+///     repeat_interval_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis())
+/// );
+///
+/// type MyJoystick<Axis, Switch> = Joystick<Axis, Switch, SomeJoystickConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// joystick_config!(
+///     pub SomeJoystickConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     x_scale: JoystickAxisScale { multiplier: 1, divisor: 1 },
+///     y_scale: JoystickAxisScale { multiplier: -1, divisor: 1 },
+///     dead_zone: 400,
+///     dead_zone_hysteresis: 100,
+///     directions: JoystickDirections::Four,
+///     direction_hysteresis: 20,
+///     repeat_delay_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis()),
+///     repeat_interval_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis())
+/// );
+///
+/// type MyJoystick<Axis, Switch> = Joystick<Axis, Switch, SomeJoystickConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeJoystickConfig;
+///
+/// joystick_config!(
+///     impl SomeJoystickConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     x_scale: JoystickAxisScale { multiplier: 1, divisor: 1 },
+///     y_scale: JoystickAxisScale { multiplier: -1, divisor: 1 },
+///     dead_zone: 400,
+///     dead_zone_hysteresis: 100,
+///     directions: JoystickDirections::Eight,
+///     direction_hysteresis: 20,
+///     repeat_delay_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis()),
+///     repeat_interval_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis())
+/// );
+///
+/// type MyJoystick<Axis, Switch> = Joystick<Axis, Switch, SomeJoystickConfig>;
+/// ```
+#[macro_export]
+macro_rules! joystick_config {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        x_scale: $x_scale:expr,
+        y_scale: $y_scale:expr,
+        dead_zone: $dead_zone:expr,
+        dead_zone_hysteresis: $dead_zone_hysteresis:expr,
+        directions: $directions:expr,
+        direction_hysteresis: $direction_hysteresis:expr,
+        repeat_delay_timer: $repeat_delay_timer_type:ty = $repeat_delay_timer_value:expr,
+        repeat_interval_timer: $repeat_interval_timer_type:ty = $repeat_interval_timer_value:expr
+    ) => {
+        $crate::debounced_input_config!(
+            impl $config_name,
+            debounce_timer: $timer_type = $timer_value
+        );
+
+        impl $crate::JoystickConfig for $config_name {
+            const X_SCALE: $crate::JoystickAxisScale = $x_scale;
+            const Y_SCALE: $crate::JoystickAxisScale = $y_scale;
+            const DEAD_ZONE: u16 = $dead_zone;
+            const DEAD_ZONE_HYSTERESIS: u16 = $dead_zone_hysteresis;
+            const DIRECTIONS: $crate::JoystickDirections = $directions;
+            const DIRECTION_HYSTERESIS: u16 = $direction_hysteresis;
+            const REPEAT_DELAY_TIMER: $repeat_delay_timer_type = $repeat_delay_timer_value;
+            const REPEAT_INTERVAL_TIMER: $repeat_interval_timer_type = $repeat_interval_timer_value;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        x_scale: $x_scale:expr,
+        y_scale: $y_scale:expr,
+        dead_zone: $dead_zone:expr,
+        dead_zone_hysteresis: $dead_zone_hysteresis:expr,
+        directions: $directions:expr,
+        direction_hysteresis: $direction_hysteresis:expr,
+        repeat_delay_timer: $repeat_delay_timer_type:ty = $repeat_delay_timer_value:expr,
+        repeat_interval_timer: $repeat_interval_timer_type:ty = $repeat_interval_timer_value:expr
+    ) => {
+        $vis struct $config_name;
+
+        joystick_config!(impl $config_name,
+            debounce_timer: $timer_type = $timer_value,
+            x_scale: $x_scale,
+            y_scale: $y_scale,
+            dead_zone: $dead_zone,
+            dead_zone_hysteresis: $dead_zone_hysteresis,
+            directions: $directions,
+            direction_hysteresis: $direction_hysteresis,
+            repeat_delay_timer: $repeat_delay_timer_type = $repeat_delay_timer_value,
+            repeat_interval_timer: $repeat_interval_timer_type = $repeat_interval_timer_value
+        );
+    };
+}
+
+/// Create a config for [`TouchKey`](crate::TouchKey).
+///
+/// # Example 1
+/// ```ignore
+/// touch_key_config!(
+///     SomeTouchKeyConfig,
+///     measure_timeout_timer: MyElapsedTimer = MyElapsedTimer::new(1.millis()),
+///     touch_threshold: 40,
+///     release_threshold: 25,
+///     baseline_shift: 4
+/// );
+///
+/// type MyTouchKey<Output, Input> = TouchKey<Output, Input, SomeTouchKeyConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// touch_key_config!(
+///     pub SomeTouchKeyConfig,
+///     measure_timeout_timer: MyElapsedTimer = MyElapsedTimer::new(1.millis()),
+///     touch_threshold: 40,
+///     release_threshold: 25,
+///     baseline_shift: 4
+/// );
+///
+/// type MyTouchKey<Output, Input> = TouchKey<Output, Input, SomeTouchKeyConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeTouchKeyConfig;
+///
+/// touch_key_config!(
+///     impl SomeTouchKeyConfig,
+///     measure_timeout_timer: MyElapsedTimer = MyElapsedTimer::new(1.millis()),
+///     touch_threshold: 40,
+///     release_threshold: 25,
+///     baseline_shift: 4
+/// );
+///
+/// type MyTouchKey<Output, Input> = TouchKey<Output, Input, SomeTouchKeyConfig>;
+/// ```
+#[macro_export]
+macro_rules! touch_key_config {
+    (
+        impl $config_name:ty,
+        measure_timeout_timer: $timer_type:ty = $timer_value:expr,
+        touch_threshold: $touch_threshold:expr,
+        release_threshold: $release_threshold:expr,
+        baseline_shift: $baseline_shift:expr
+    ) => {
+        impl $crate::TouchKeyConfig for $config_name {
+            type Timer = $timer_type;
+            const MEASURE_TIMEOUT_TIMER: $timer_type = $timer_value;
+            const TOUCH_THRESHOLD: u16 = $touch_threshold;
+            const RELEASE_THRESHOLD: u16 = $release_threshold;
+            const BASELINE_SHIFT: u8 = $baseline_shift;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        measure_timeout_timer: $timer_type:ty = $timer_value:expr,
+        touch_threshold: $touch_threshold:expr,
+        release_threshold: $release_threshold:expr,
+        baseline_shift: $baseline_shift:expr
+    ) => {
+        $vis struct $config_name;
+
+        touch_key_config!(impl $config_name,
+            measure_timeout_timer: $timer_type = $timer_value,
+            touch_threshold: $touch_threshold,
+            release_threshold: $release_threshold,
+            baseline_shift: $baseline_shift
+        );
+    };
+}
+
+/// Create a config for [`Output`](crate::Output).
+///
+/// # Example 1
+/// ```ignore
+/// output_config!(
+///     SomeOutputConfig,
+///     min_on_timer: MyElapsedTimer = MyElapsedTimer::new(30.secs()),
+///     min_off_timer: MyElapsedTimer = MyElapsedTimer::new(3.mins())
+/// );
+///
+/// type MyOutput<Switch> = Output<Switch, SomeOutputConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// output_config!(
+///     pub SomeOutputConfig,
+///     min_on_timer: MyElapsedTimer = MyElapsedTimer::new(30.secs()),
+///     min_off_timer: MyElapsedTimer = MyElapsedTimer::new(3.mins()),
+///     max_on_timer: MyElapsedTimer = MyElapsedTimer::new(2.hours())
+/// );
+///
+/// type MyOutput<Switch> = Output<Switch, SomeOutputConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeOutputConfig;
+///
+/// output_config!(
+///     impl SomeOutputConfig,
+///     min_on_timer: MyElapsedTimer = MyElapsedTimer::new(30.secs()),
+///     min_off_timer: MyElapsedTimer = MyElapsedTimer::new(3.mins())
+/// );
+///
+/// type MyOutput<Switch> = Output<Switch, SomeOutputConfig>;
+/// ```
+#[macro_export]
+macro_rules! output_config {
+    (
+        impl $config_name:ty,
+        min_on_timer: $timer_type:ty = $min_on_timer_value:expr,
+        min_off_timer: $min_off_timer_type:ty = $min_off_timer_value:expr
+        $(, max_on_timer: $max_on_timer_type:ty = $max_on_timer_value:expr)?
+    ) => {
+        impl $crate::OutputConfig for $config_name {
+            type Timer = $timer_type;
+            const MIN_ON_TIMER: $timer_type = $min_on_timer_value;
+            const MIN_OFF_TIMER: $min_off_timer_type = $min_off_timer_value;
+            $(const MAX_ON_TIMER: Option<$max_on_timer_type> = Some($max_on_timer_value);)?
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        min_on_timer: $timer_type:ty = $min_on_timer_value:expr,
+        min_off_timer: $min_off_timer_type:ty = $min_off_timer_value:expr
+        $(, max_on_timer: $max_on_timer_type:ty = $max_on_timer_value:expr)?
+    ) => {
+        $vis struct $config_name;
+
+        output_config!(impl $config_name,
+            min_on_timer: $timer_type = $min_on_timer_value,
+            min_off_timer: $min_off_timer_type = $min_off_timer_value
+            $(, max_on_timer: $max_on_timer_type = $max_on_timer_value)?
+        );
+    };
+}
+
+/// Create a config for [`TwoHandControl`](crate::TwoHandControl).
+///
+/// # Example 1
+/// ```ignore
+/// two_hand_control_config!(
+///     SomeTwoHandControlConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis())
+/// );
+///
+/// type MyTwoHandControl<Switch> = TwoHandControl<Switch, SomeTwoHandControlConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// two_hand_control_config!(
+///     pub SomeTwoHandControlConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis())
+/// );
+///
+/// type MyTwoHandControl<Switch> = TwoHandControl<Switch, SomeTwoHandControlConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeTwoHandControlConfig;
+///
+/// two_hand_control_config!(
+///     impl SomeTwoHandControlConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis())
+/// );
+///
+/// type MyTwoHandControl<Switch> = TwoHandControl<Switch, SomeTwoHandControlConfig>;
+/// ```
+#[macro_export]
+macro_rules! two_hand_control_config {
+    (
+        impl $config_name:ty,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        simultaneity_timer: $simultaneity_timer_type:ty = $simultaneity_timer_value:expr
+    ) => {
+        $crate::debounced_input_config!(
+            impl $config_name,
+            debounce_timer: $timer_type = $timer_value
+        );
+
+        impl $crate::TwoHandControlConfig for $config_name {
+            const SIMULTANEITY_TIMER: $simultaneity_timer_type = $simultaneity_timer_value;
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        debounce_timer: $timer_type:ty = $timer_value:expr,
+        simultaneity_timer: $simultaneity_timer_type:ty = $simultaneity_timer_value:expr
+    ) => {
+        $vis struct $config_name;
+
+        two_hand_control_config!(impl $config_name,
+            debounce_timer: $timer_type = $timer_value,
+            simultaneity_timer: $simultaneity_timer_type = $simultaneity_timer_value
+        );
+    };
+}
+
+/// Create a config for [`DualChannelInput`](crate::DualChannelInput).
+///
+/// # Example 1
+/// ```ignore
+/// dual_channel_input_config!(
+///     SomeDualChannelInputConfig,
+///     discrepancy_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis())
+/// );
+///
+/// type MyDualChannelInput<SwitchA, SwitchB> = DualChannelInput<SwitchA, SwitchB, SomeDualChannelInputConfig>;
+/// ```
+///
+/// # Example 2
+/// ```ignore
+/// dual_channel_input_config!(
+///     pub SomeDualChannelInputConfig,
+///     discrepancy_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis()),
+///     safe_level_a: true,
+///     safe_level_b: false
+/// );
+///
+/// type MyDualChannelInput<SwitchA, SwitchB> = DualChannelInput<SwitchA, SwitchB, SomeDualChannelInputConfig>;
+/// ```
+///
+/// # Example 3
+/// ```ignore
+/// pub struct SomeDualChannelInputConfig;
+///
+/// dual_channel_input_config!(
+///     impl SomeDualChannelInputConfig,
+///     discrepancy_timer: MyElapsedTimer = MyElapsedTimer::new(100.millis())
+/// );
+///
+/// type MyDualChannelInput<SwitchA, SwitchB> = DualChannelInput<SwitchA, SwitchB, SomeDualChannelInputConfig>;
+/// ```
+#[macro_export]
+macro_rules! dual_channel_input_config {
+    (
+        impl $config_name:ty,
+        discrepancy_timer: $timer_type:ty = $timer_value:expr
+        $(, safe_level_a: $safe_level_a:expr)?
+        $(, safe_level_b: $safe_level_b:expr)?
+    ) => {
+        impl $crate::DualChannelInputConfig for $config_name {
+            type Timer = $timer_type;
+            const DISCREPANCY_TIMER: $timer_type = $timer_value;
+            $(const SAFE_LEVEL_A: bool = $safe_level_a;)?
+            $(const SAFE_LEVEL_B: bool = $safe_level_b;)?
+        }
+    };
+    (
+        $vis:vis $config_name:ident,
+        discrepancy_timer: $timer_type:ty = $timer_value:expr
+        $(, safe_level_a: $safe_level_a:expr)?
+        $(, safe_level_b: $safe_level_b:expr)?
+    ) => {
+        $vis struct $config_name;
+
+        dual_channel_input_config!(
+            impl $config_name,
+            discrepancy_timer: $timer_type = $timer_value
+            $(, safe_level_a: $safe_level_a)?
+            $(, safe_level_b: $safe_level_b)?
+        );
+    };
+}
@@ -0,0 +1,409 @@
+use crate::{DebouncedInputEvent, EncoderEvent};
+
+use core::ops::Range;
+
+/// The item of a static menu tree of [`Menu`](crate::Menu).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MenuItem {
+    /// The label of the item.
+    pub label: &'static str,
+    /// The kind of the item.
+    pub kind: MenuItemKind,
+}
+
+/// The kind of [`MenuItem`](crate::MenuItem).
+///
+/// Editable items refer to a value slot of [`Menu`](crate::Menu) by `id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItemKind {
+    /// Nested menu with items.
+    Submenu(&'static [MenuItem]),
+    /// Action with id, it's reported upon enter.
+    Action(usize),
+    /// Editable numeric value in bounds `min..=max` that changes by `step`.
+    Number {
+        id: usize,
+        min: i32,
+        max: i32,
+        step: i32,
+    },
+    /// Editable value that selects one of options, the value is an index of option.
+    Enum {
+        id: usize,
+        options: &'static [&'static str],
+    },
+    /// Editable boolean value, it's toggled upon enter.
+    Bool { id: usize },
+}
+
+/// The value of an editable [`MenuItem`](crate::MenuItem) prepared for drawing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuValue {
+    Number(i32),
+    Option(&'static str),
+    Bool(bool),
+}
+
+/// The navigation input of [`Menu`](crate::Menu).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuInput {
+    /// Move the cursor (or increase the edited value) forward.
+    Next,
+    /// Move the cursor (or decrease the edited value) backward.
+    Previous,
+    /// Enter a submenu, start or commit an edit, trigger an action.
+    Enter,
+    /// Exit a submenu or cancel an edit.
+    Back,
+}
+
+/// The event result of handle an input by [`Menu`](crate::Menu).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuEvent {
+    /// Nothing is changed.
+    NoChange,
+    /// The cursor is moved to the item with index.
+    CursorMoved(usize),
+    /// A submenu is entered.
+    Entered,
+    /// A submenu is exited.
+    Exited,
+    /// An action with id is triggered.
+    Action(usize),
+    /// An edit of the value with id is started.
+    EditStarted(usize),
+    /// The edited (not committed yet) value with id is changed.
+    EditChanged { id: usize, value: i32 },
+    /// The value with id is committed.
+    ValueChanged { id: usize, value: i32 },
+    /// An edit of the value with id is cancelled.
+    EditCancelled(usize),
+}
+
+/// The renderer-agnostic state of [`Menu`](crate::Menu) that describes what to draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MenuView<'a, const VALUES: usize> {
+    /// The title of the current menu level.
+    pub title: &'static str,
+    /// The items of the current menu level.
+    pub items: &'static [MenuItem],
+    /// The index of the item under the cursor.
+    pub cursor: usize,
+    /// The edited value if the item under the cursor is being edited.
+    pub editing: Option<i32>,
+    values: &'a [i32; VALUES],
+}
+
+#[derive(Clone, Copy)]
+struct MenuLevel {
+    title: &'static str,
+    items: &'static [MenuItem],
+    cursor: usize,
+}
+
+/// Concrete implementation of hierarchical menu navigation engine.
+///
+/// The menu is driven by [`MenuInput`](crate::MenuInput)s or directly by events of
+/// [`Encoder`](crate::Encoder) and [`DebouncedInput`](crate::DebouncedInput)s.
+///
+/// # Type Params
+/// `DEPTH` - The maximum depth of the menu tree, the root level is included, must be more than 0.
+///
+/// `VALUES` - The number of value slots for editable items.
+///
+/// # Example
+/// ```ignore
+/// const SETTINGS: &[MenuItem] = &[
+///     MenuItem {
+///         label: "Volume",
+///         kind: MenuItemKind::Number { id: 0, min: 0, max: 10, step: 1 },
+///     },
+///     MenuItem {
+///         label: "Mode",
+///         kind: MenuItemKind::Enum { id: 1, options: &["Auto", "Manual"] },
+///     },
+///     MenuItem { label: "Beep", kind: MenuItemKind::Bool { id: 2 } },
+/// ];
+///
+/// const ROOT: &[MenuItem] = &[
+///     MenuItem { label: "Settings", kind: MenuItemKind::Submenu(SETTINGS) },
+///     MenuItem { label: "Reset", kind: MenuItemKind::Action(0) },
+/// ];
+///
+/// let mut menu = Menu::<2, 3>::new("Main", ROOT, [5, 0, 1]);
+///
+/// loop {
+///     menu.handle_encoder_event(encoder.update().unwrap());
+///     menu.handle_enter_event(select.update().unwrap());
+///     menu.handle_back_event(back.update().unwrap());
+///
+///     draw(menu.view());
+/// }
+/// ```
+pub struct Menu<const DEPTH: usize, const VALUES: usize> {
+    levels: [MenuLevel; DEPTH],
+    depth: usize,
+    editing: Option<i32>,
+    values: [i32; VALUES],
+}
+
+impl MenuItem {
+    /// Returns the value id of the item, `None` if the item isn't editable.
+    pub fn value_id(&self) -> Option<usize> {
+        match self.kind {
+            MenuItemKind::Number { id, .. }
+            | MenuItemKind::Enum { id, .. }
+            | MenuItemKind::Bool { id } => Some(id),
+            MenuItemKind::Submenu(_) | MenuItemKind::Action(_) => None,
+        }
+    }
+
+    /// Converts the raw value to [`MenuValue`], `None` if the item isn't editable.
+    pub fn to_menu_value(&self, value: i32) -> Option<MenuValue> {
+        match self.kind {
+            MenuItemKind::Number { .. } => Some(MenuValue::Number(value)),
+            MenuItemKind::Enum { options, .. } => usize::try_from(value)
+                .ok()
+                .and_then(|index| options.get(index))
+                .map(|option| MenuValue::Option(option)),
+            MenuItemKind::Bool { .. } => Some(MenuValue::Bool(value != 0)),
+            MenuItemKind::Submenu(_) | MenuItemKind::Action(_) => None,
+        }
+    }
+
+    fn step(&self, value: i32, forward: bool) -> i32 {
+        let (min, max, step) = match self.kind {
+            MenuItemKind::Number { min, max, step, .. } => (min, max, step),
+            MenuItemKind::Enum { options, .. } => (0, options.len() as i32 - 1, 1),
+            MenuItemKind::Bool { .. } => (0, 1, 1),
+            MenuItemKind::Submenu(_) | MenuItemKind::Action(_) => return value,
+        };
+
+        let value = if forward {
+            value.saturating_add(step)
+        } else {
+            value.saturating_sub(step)
+        };
+
+        value.clamp(min, max.max(min))
+    }
+}
+
+impl<'a, const VALUES: usize> MenuView<'a, VALUES> {
+    /// Returns the value of the item with index prepared for drawing,
+    /// the edited value is returned for the item under the cursor if it's being edited.
+    pub fn value(&self, index: usize) -> Option<MenuValue> {
+        let item = self.items.get(index)?;
+        let value = match self.editing {
+            Some(value) if index == self.cursor => value,
+            _ => *self.values.get(item.value_id()?)?,
+        };
+
+        item.to_menu_value(value)
+    }
+
+    /// Returns the range of items to draw on a screen with `rows` lines, the cursor is always visible.
+    pub fn visible_range(&self, rows: usize) -> Range<usize> {
+        let start = (self.cursor + 1).saturating_sub(rows);
+        start..(start + rows).min(self.items.len())
+    }
+}
+
+impl<const DEPTH: usize, const VALUES: usize> Menu<DEPTH, VALUES> {
+    /// Creates a new [`Menu<DEPTH, VALUES>`].
+    ///
+    /// `title` - the title of the root level.
+    ///
+    /// `items` - the items of the root level.
+    ///
+    /// `values` - initial values of editable items.
+    pub fn new(title: &'static str, items: &'static [MenuItem], values: [i32; VALUES]) -> Self {
+        const { assert!(DEPTH > 0) };
+
+        let root = MenuLevel {
+            title,
+            items,
+            cursor: 0,
+        };
+
+        Menu {
+            levels: [root; DEPTH],
+            depth: 0,
+            editing: None,
+            values,
+        }
+    }
+
+    /// Returns the state of the menu that describes what to draw.
+    pub fn view(&self) -> MenuView<'_, VALUES> {
+        let level = &self.levels[self.depth];
+
+        MenuView {
+            title: level.title,
+            items: level.items,
+            cursor: level.cursor,
+            editing: self.editing,
+            values: &self.values,
+        }
+    }
+
+    /// Returns the depth of the current level, the root level is `0`.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns true if the item under the cursor is being edited.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Returns the value with id.
+    pub fn value(&self, id: usize) -> Option<i32> {
+        self.values.get(id).copied()
+    }
+
+    /// Sets the value with id, returns false if id is out of value slots.
+    pub fn set_value(&mut self, id: usize, value: i32) -> bool {
+        match self.values.get_mut(id) {
+            Some(slot) => {
+                *slot = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Borrow all values.
+    pub fn values(&self) -> &[i32; VALUES] {
+        &self.values
+    }
+
+    /// Handles a navigation input.
+    pub fn handle(&mut self, input: MenuInput) -> MenuEvent {
+        let level = self.levels[self.depth];
+        let item = match level.items.get(level.cursor) {
+            Some(item) => item,
+            None => return self.handle_empty(input),
+        };
+
+        match (self.editing, input) {
+            (Some(value), MenuInput::Next | MenuInput::Previous) => {
+                let new_value = item.step(value, input == MenuInput::Next);
+                self.editing = Some(new_value);
+
+                match (item.value_id(), new_value != value) {
+                    (Some(id), true) => MenuEvent::EditChanged {
+                        id,
+                        value: new_value,
+                    },
+                    _ => MenuEvent::NoChange,
+                }
+            }
+            (Some(value), MenuInput::Enter) => {
+                self.editing = None;
+                self.commit(item, value)
+            }
+            (Some(_), MenuInput::Back) => {
+                self.editing = None;
+                item.value_id()
+                    .map_or(MenuEvent::NoChange, MenuEvent::EditCancelled)
+            }
+            (None, MenuInput::Next) => self.move_cursor(level.cursor + 1),
+            (None, MenuInput::Previous) => match level.cursor.checked_sub(1) {
+                Some(cursor) => self.move_cursor(cursor),
+                None => MenuEvent::NoChange,
+            },
+            (None, MenuInput::Enter) => self.enter(item),
+            (None, MenuInput::Back) => self.exit(),
+        }
+    }
+
+    /// Handles an event of [`Encoder`](crate::Encoder), the clockwise turn is [`MenuInput::Next`].
+    pub fn handle_encoder_event(&mut self, event: EncoderEvent) -> MenuEvent {
+        match event {
+            EncoderEvent::NoTurn => MenuEvent::NoChange,
+            EncoderEvent::ClockwiseTurn => self.handle(MenuInput::Next),
+            EncoderEvent::CounterClockwiseTurn => self.handle(MenuInput::Previous),
+        }
+    }
+
+    /// Handles an event of [`DebouncedInput`](crate::DebouncedInput) of the enter button,
+    /// the rise is [`MenuInput::Enter`].
+    pub fn handle_enter_event(&mut self, event: DebouncedInputEvent) -> MenuEvent {
+        match event {
+            DebouncedInputEvent::Rise => self.handle(MenuInput::Enter),
+            _ => MenuEvent::NoChange,
+        }
+    }
+
+    /// Handles an event of [`DebouncedInput`](crate::DebouncedInput) of the back button,
+    /// the rise is [`MenuInput::Back`].
+    pub fn handle_back_event(&mut self, event: DebouncedInputEvent) -> MenuEvent {
+        match event {
+            DebouncedInputEvent::Rise => self.handle(MenuInput::Back),
+            _ => MenuEvent::NoChange,
+        }
+    }
+
+    fn handle_empty(&mut self, input: MenuInput) -> MenuEvent {
+        match input {
+            MenuInput::Back => self.exit(),
+            _ => MenuEvent::NoChange,
+        }
+    }
+
+    fn move_cursor(&mut self, cursor: usize) -> MenuEvent {
+        let level = &mut self.levels[self.depth];
+        if cursor < level.items.len() && cursor != level.cursor {
+            level.cursor = cursor;
+            MenuEvent::CursorMoved(cursor)
+        } else {
+            MenuEvent::NoChange
+        }
+    }
+
+    fn enter(&mut self, item: &MenuItem) -> MenuEvent {
+        match item.kind {
+            MenuItemKind::Submenu(items) if self.depth + 1 < DEPTH => {
+                self.depth += 1;
+                self.levels[self.depth] = MenuLevel {
+                    title: item.label,
+                    items,
+                    cursor: 0,
+                };
+                MenuEvent::Entered
+            }
+            MenuItemKind::Submenu(_) => MenuEvent::NoChange,
+            MenuItemKind::Action(id) => MenuEvent::Action(id),
+            MenuItemKind::Bool { id } => match self.value(id) {
+                Some(value) => self.commit(item, (value == 0) as i32),
+                None => MenuEvent::NoChange,
+            },
+            MenuItemKind::Number { id, .. } | MenuItemKind::Enum { id, .. } => {
+                match self.value(id) {
+                    Some(value) => {
+                        self.editing = Some(value);
+                        MenuEvent::EditStarted(id)
+                    }
+                    None => MenuEvent::NoChange,
+                }
+            }
+        }
+    }
+
+    fn exit(&mut self) -> MenuEvent {
+        if self.depth > 0 {
+            self.depth -= 1;
+            MenuEvent::Exited
+        } else {
+            MenuEvent::NoChange
+        }
+    }
+
+    fn commit(&mut self, item: &MenuItem, value: i32) -> MenuEvent {
+        match item.value_id() {
+            Some(id) if self.set_value(id, value) => MenuEvent::ValueChanged { id, value },
+            _ => MenuEvent::NoChange,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{Control, OutputError};
+
+use core::marker::PhantomData;
+use switch_hal::OutputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`Output`](crate::Output).
+pub trait OutputConfig {
+    /// Elapsed timer type that used for [`Output`](crate::Output).
+    type Timer: ElapsedTimer;
+
+    /// Elapsed timer instance that used for [`Output`](crate::Output).
+    /// The output isn't switched off until this timer is elapsed since it's switched on.
+    const MIN_ON_TIMER: Self::Timer;
+
+    /// Elapsed timer instance that used for [`Output`](crate::Output).
+    /// The output isn't switched on until this timer is elapsed since it's switched off.
+    const MIN_OFF_TIMER: Self::Timer;
+
+    /// Elapsed timer instance that used for [`Output`](crate::Output).
+    /// The output is forced off and the request is reset if this timer is elapsed since it's switched on,
+    /// `None` means no limit.
+    const MAX_ON_TIMER: Option<Self::Timer> = None;
+}
+
+/// The state machine of [`Output`](crate::Output).
+pub enum OutputState<T> {
+    Unknown,
+    Off(Option<T>),
+    On(T),
+}
+
+/// Concrete implementation of output (relay, compressor and etc.) that must not short-cycle.
+///
+/// The requested state is applied by update when minimum on/off times are elapsed.
+/// The output is switched off upon creation and can be switched on immediately.
+/// If switching off upon creation is failed, it's retried by the next update.
+///
+/// # Type Params
+/// `Switch` - [`OutputSwitch`](switch_hal::OutputSwitch) that drives the output.
+///
+/// `Config` - [`OutputConfig`](crate::OutputConfig) that provides configs for output.
+///
+/// # Example
+/// ```ignore
+/// output_config!(
+///     SomeOutputConfig,
+///     min_on_timer: MyElapsedTimer = MyElapsedTimer::new(30.secs()),
+///     min_off_timer: MyElapsedTimer = MyElapsedTimer::new(3.mins()),
+///     max_on_timer: MyElapsedTimer = MyElapsedTimer::new(2.hours())
+/// );
+///
+/// type MyOutput<Switch> = Output<Switch, SomeOutputConfig>;
+///
+/// let mut compressor = MyOutput::new(pin.into_active_high_switch());
+///
+/// loop {
+///     compressor.set(temperature() > setpoint());
+///
+///     match compressor.update().unwrap() {
+///         OutputEvent::Steady => {}
+///         OutputEvent::Switched(on) => log_compressor(on),
+///         OutputEvent::Deferred => do_something_while_deferred(),
+///     }
+/// }
+/// ```
+pub struct Output<Switch: OutputSwitch, Config: OutputConfig> {
+    output_switch: Switch,
+    state: OutputState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    requested: bool,
+    config: PhantomData<Config>,
+}
+
+/// The event result of update [`Output`](crate::Output).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputEvent {
+    /// The output is in the requested state.
+    Steady,
+    /// The output is switched on (`true`) or off (`false`).
+    Switched(bool),
+    /// The requested state is deferred by minimum on or off time.
+    Deferred,
+}
+
+impl<Switch: OutputSwitch, Config: OutputConfig> Output<Switch, Config> {
+    /// Creates a new [`Output<Switch, Config>`] from a concrete `Switch` and switches it off.
+    ///
+    /// `output_switch` - an concrete instance of `Switch`.
+    pub fn new(mut output_switch: Switch) -> Self {
+        let state = match output_switch.off() {
+            Ok(()) => OutputState::Off(None),
+            Err(_) => OutputState::Unknown,
+        };
+
+        Output {
+            output_switch,
+            state,
+            requested: false,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Creates a new [`Output<Switch, Config>`] from a concrete `Switch` and switches it off,
+    /// unlike [`new`](Output::new) it reports an error of switching off.
+    ///
+    /// `output_switch` - an concrete instance of `Switch`.
+    /// # Errors
+    /// This function will return an error if switching off `output_switch` is failed.
+    pub fn try_new(mut output_switch: Switch) -> Result<Self, <Self as Control>::Error> {
+        output_switch.off().map_err(OutputError::OutputSwitch)?;
+
+        Ok(Output {
+            output_switch,
+            state: OutputState::Off(None),
+            requested: false,
+            config: PhantomData::<Config>,
+        })
+    }
+
+    /// Requests to switch the output on (`true`) or off (`false`), the request is applied by update.
+    pub fn set(&mut self, on: bool) {
+        self.requested = on;
+    }
+
+    /// Returns the requested state.
+    pub fn is_requested(&self) -> bool {
+        self.requested
+    }
+
+    /// Returns the is switched on state.
+    pub fn is_on(&self) -> bool {
+        matches!(self.state, OutputState::On(_))
+    }
+
+    /// Borrow `Switch`.
+    pub fn borrow_output_switch(&self) -> &Switch {
+        &self.output_switch
+    }
+
+    /// Consumes `self` and release `Switch`.
+    pub fn release_output_switch(self) -> Switch {
+        self.output_switch
+    }
+
+    fn switch_off(
+        &mut self,
+        now: <Config::Timer as ElapsedTimer>::Timestamp,
+    ) -> Result<OutputEvent, <Self as Control>::Error> {
+        self.output_switch
+            .off()
+            .map_err(OutputError::OutputSwitch)?;
+        self.state = OutputState::Off(Some(now));
+        Ok(OutputEvent::Switched(false))
+    }
+}
+
+impl<Switch: OutputSwitch, Config: OutputConfig> Control for Output<Switch, Config> {
+    type Event = OutputEvent;
+    type Error = OutputError<
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+        Switch::Error,
+    >;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        match &self.state {
+            OutputState::Unknown => self.switch_off(now),
+            OutputState::On(since) => {
+                if let Some(max_on_timer) = &Config::MAX_ON_TIMER {
+                    if max_on_timer
+                        .timeout(since, &now)
+                        .map_err(OutputError::ElapsedTimer)?
+                    {
+                        self.requested = false;
+                        return self.switch_off(now);
+                    }
+                }
+
+                if self.requested {
+                    Ok(OutputEvent::Steady)
+                } else if Config::MIN_ON_TIMER
+                    .timeout(since, &now)
+                    .map_err(OutputError::ElapsedTimer)?
+                {
+                    self.switch_off(now)
+                } else {
+                    Ok(OutputEvent::Deferred)
+                }
+            }
+            OutputState::Off(since) => {
+                if !self.requested {
+                    return Ok(OutputEvent::Steady);
+                }
+
+                let min_off_elapsed = match since {
+                    Some(since) => Config::MIN_OFF_TIMER
+                        .timeout(since, &now)
+                        .map_err(OutputError::ElapsedTimer)?,
+                    None => true,
+                };
+
+                if min_off_elapsed {
+                    self.output_switch.on().map_err(OutputError::OutputSwitch)?;
+                    self.state = OutputState::On(now);
+                    Ok(OutputEvent::Switched(true))
+                } else {
+                    Ok(OutputEvent::Deferred)
+                }
+            }
+        }
+    }
+}
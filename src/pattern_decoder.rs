@@ -0,0 +1,268 @@
+use crate::{Control, DebouncedInput, DebouncedInputConfig, DebouncedInputEvent, Error};
+
+use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`PatternDecoder`](crate::PatternDecoder).
+pub trait PatternDecoderConfig: DebouncedInputConfig {
+    /// Elapsed timer instance that used for [`PatternDecoder`](crate::PatternDecoder).
+    /// A press is long (dash) if this timer is elapsed while the input is high, otherwise it's short (dot).
+    const LONG_PRESS_TIMER: Self::Timer;
+
+    /// Elapsed timer instance that used for [`PatternDecoder`](crate::PatternDecoder).
+    /// The pattern is finished if this timer is elapsed since the last release.
+    const GAP_TIMER: Self::Timer;
+}
+
+/// The state machine of [`PatternDecoder`](crate::PatternDecoder).
+pub enum PatternDecoderState<T> {
+    Idle,
+    Pressed(T),
+    Released(T),
+}
+
+/// The pattern of short and long presses.
+///
+/// Presses are stored in order, the first press is the least significant bit,
+/// `0` is a short press (dot) and `1` is a long press (dash).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PressPattern {
+    bits: u16,
+    len: u8,
+}
+
+/// Concrete implementation of short/long press pattern decoder over one debounced input.
+///
+/// # Type Params
+/// `Switch` - [`InputSwitch`](switch_hal::InputSwitch) that provides input for decoding.
+///
+/// `Config` - [`PatternDecoderConfig`](crate::PatternDecoderConfig) that provides configs for decoding.
+///
+/// # Example
+/// ```ignore
+/// pattern_decoder_config!(
+///     SomePatternDecoderConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     long_press_timer: MyElapsedTimer = MyElapsedTimer::new(300.millis()),
+///     gap_timer: MyElapsedTimer = MyElapsedTimer::new(800.millis())
+/// );
+///
+/// type MyPatternDecoder<Switch> = PatternDecoder<Switch, SomePatternDecoderConfig>;
+///
+/// let mut pattern_decoder = MyPatternDecoder::new(pin.into_active_low_switch());
+///
+/// loop {
+///     match pattern_decoder.update().unwrap() {
+///         PatternDecoderEvent::NoPattern => {}
+///         PatternDecoderEvent::Pattern(pattern) => match pattern.to_morse_char() {
+///             Some(symbol) => do_something_with_symbol(symbol),
+///             None => do_something_with_bits(pattern.bits(), pattern.len()),
+///         },
+///     }
+/// }
+/// ```
+pub struct PatternDecoder<Switch: InputSwitch, Config: PatternDecoderConfig> {
+    debounced_input: DebouncedInput<Switch, Config>,
+    state: PatternDecoderState<<Config::Timer as ElapsedTimer>::Timestamp>,
+    pattern: PressPattern,
+}
+
+/// The event result of update [`PatternDecoder`](crate::PatternDecoder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternDecoderEvent {
+    /// The pattern isn't finished yet.
+    NoPattern,
+    /// The pattern is finished.
+    Pattern(PressPattern),
+}
+
+const MORSE_TABLE: [(&str, char); 36] = [
+    (".-", 'A'),
+    ("-...", 'B'),
+    ("-.-.", 'C'),
+    ("-..", 'D'),
+    (".", 'E'),
+    ("..-.", 'F'),
+    ("--.", 'G'),
+    ("....", 'H'),
+    ("..", 'I'),
+    (".---", 'J'),
+    ("-.-", 'K'),
+    (".-..", 'L'),
+    ("--", 'M'),
+    ("-.", 'N'),
+    ("---", 'O'),
+    (".--.", 'P'),
+    ("--.-", 'Q'),
+    (".-.", 'R'),
+    ("...", 'S'),
+    ("-", 'T'),
+    ("..-", 'U'),
+    ("...-", 'V'),
+    (".--", 'W'),
+    ("-..-", 'X'),
+    ("-.--", 'Y'),
+    ("--..", 'Z'),
+    ("-----", '0'),
+    (".----", '1'),
+    ("..---", '2'),
+    ("...--", '3'),
+    ("....-", '4'),
+    (".....", '5'),
+    ("-....", '6'),
+    ("--...", '7'),
+    ("---..", '8'),
+    ("----.", '9'),
+];
+
+impl PressPattern {
+    /// The maximum number of presses in a pattern.
+    pub const MAX_LEN: u8 = u16::BITS as u8;
+
+    /// Creates a new [`PressPattern`] from bits and the number of presses.
+    pub fn new(bits: u16, len: u8) -> Self {
+        let len = len.min(Self::MAX_LEN);
+        let mask = if len == Self::MAX_LEN {
+            u16::MAX
+        } else {
+            (1 << len) - 1
+        };
+
+        PressPattern {
+            bits: bits & mask,
+            len,
+        }
+    }
+
+    /// Creates a new [`PressPattern`] from a Morse string of `.` and `-`,
+    /// returns `None` if the string contains other symbols or is too long.
+    pub fn from_morse(morse: &str) -> Option<Self> {
+        if morse.len() > Self::MAX_LEN as usize {
+            return None;
+        }
+
+        let mut pattern = PressPattern::default();
+        for symbol in morse.bytes() {
+            match symbol {
+                b'.' => pattern.push(false),
+                b'-' => pattern.push(true),
+                _ => return None,
+            }
+        }
+
+        Some(pattern)
+    }
+
+    /// Returns the bits of the pattern.
+    pub fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// Returns the number of presses in the pattern.
+    pub fn len(&self) -> u8 {
+        self.len
+    }
+
+    /// Returns true if the pattern has no presses.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the press with `index` is long, `None` if `index` is out of the pattern.
+    pub fn is_long(&self, index: u8) -> Option<bool> {
+        (index < self.len).then(|| self.bits & 1 << index != 0)
+    }
+
+    /// Decodes the pattern as a Morse character (latin letters and digits).
+    pub fn to_morse_char(&self) -> Option<char> {
+        MORSE_TABLE
+            .iter()
+            .find(|(morse, _)| PressPattern::from_morse(morse) == Some(*self))
+            .map(|(_, symbol)| *symbol)
+    }
+
+    fn push(&mut self, long: bool) {
+        if long {
+            self.bits |= 1 << self.len;
+        }
+        self.len += 1;
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == Self::MAX_LEN
+    }
+}
+
+impl<Switch: InputSwitch, Config: PatternDecoderConfig> PatternDecoder<Switch, Config> {
+    /// Creates a new [`PatternDecoder<Switch, Config>`] from a concrete `Switch`.
+    ///
+    /// `input_switch` - an concrete instance of `Switch`.
+    pub fn new(input_switch: Switch) -> Self {
+        PatternDecoder {
+            debounced_input: DebouncedInput::new(input_switch),
+            state: PatternDecoderState::Idle,
+            pattern: PressPattern::default(),
+        }
+    }
+
+    /// Returns the unfinished pattern.
+    pub fn pending_pattern(&self) -> PressPattern {
+        self.pattern
+    }
+
+    /// Borrow the debounced input.
+    pub fn borrow_debounced_input(&self) -> &DebouncedInput<Switch, Config> {
+        &self.debounced_input
+    }
+
+    /// Consumes `self` and release `Switch`.
+    pub fn release_input_switch(self) -> Switch {
+        self.debounced_input.release_input_switch()
+    }
+
+    fn take_pattern(&mut self) -> PatternDecoderEvent {
+        self.state = PatternDecoderState::Idle;
+        PatternDecoderEvent::Pattern(core::mem::take(&mut self.pattern))
+    }
+}
+
+impl<Switch: InputSwitch, Config: PatternDecoderConfig> Control for PatternDecoder<Switch, Config> {
+    type Event = PatternDecoderEvent;
+    type Error = <DebouncedInput<Switch, Config> as Control>::Error;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let event = self.debounced_input.update()?;
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        Ok(match (event, &self.state) {
+            (DebouncedInputEvent::Rise, _) => {
+                self.state = PatternDecoderState::Pressed(now);
+                PatternDecoderEvent::NoPattern
+            }
+            (DebouncedInputEvent::Fall, PatternDecoderState::Pressed(start)) => {
+                let long = Config::LONG_PRESS_TIMER
+                    .timeout(start, &now)
+                    .map_err(Error::ElapsedTimer)?;
+                self.pattern.push(long);
+
+                if self.pattern.is_full() {
+                    self.take_pattern()
+                } else {
+                    self.state = PatternDecoderState::Released(now);
+                    PatternDecoderEvent::NoPattern
+                }
+            }
+            (DebouncedInputEvent::Low, PatternDecoderState::Released(start)) => {
+                if Config::GAP_TIMER
+                    .timeout(start, &now)
+                    .map_err(Error::ElapsedTimer)?
+                {
+                    self.take_pattern()
+                } else {
+                    PatternDecoderEvent::NoPattern
+                }
+            }
+            _ => PatternDecoderEvent::NoPattern,
+        })
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{AnalogInput, Control};
+
+use core::marker::PhantomData;
+
+/// The smoothing of raw values of [`Potentiometer`](crate::Potentiometer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PotentiometerSmoothing {
+    /// Raw values are used as is.
+    None,
+    /// Exponential moving average with alpha `1 / 2^shift`.
+    Exponential(u8),
+    /// Moving average of the last `window` raw values, the window is clamped to `1..=MAX_WINDOW`.
+    MovingAverage(u8),
+}
+
+impl PotentiometerSmoothing {
+    /// The maximum window of [`PotentiometerSmoothing::MovingAverage`].
+    pub const MAX_WINDOW: u8 = 16;
+}
+
+/// Represents a config for [`Potentiometer`](crate::Potentiometer).
+pub trait PotentiometerConfig {
+    /// The maximum raw value of analog input, e.g. `4095` for 12-bit ADC.
+    const MAX_VALUE: u16;
+
+    /// The number of quantized positions of potentiometer.
+    const POSITIONS: u16;
+
+    /// The smoothing of raw values.
+    const SMOOTHING: PotentiometerSmoothing;
+
+    /// The number of raw counts at both ends of travel that are clamped to the first and the last positions.
+    const DEAD_BAND: u16;
+
+    /// The number of raw counts that value must pass beyond the boundary of current position to change it,
+    /// must be less than the width of one position.
+    const HYSTERESIS: u16;
+}
+
+/// Concrete implementation of analog potentiometer.
+///
+/// # Type Params
+/// `Input` - [`AnalogInput`](crate::AnalogInput) that provides raw values of potentiometer.
+///
+/// `Config` - [`PotentiometerConfig`](crate::PotentiometerConfig) that provides configs for potentiometer.
+///
+/// # Example
+/// ```ignore
+/// potentiometer_config!(
+///     SomePotentiometerConfig,
+///     max_value: 4095,
+///     positions: 100,
+///     smoothing: PotentiometerSmoothing::Exponential(3),
+///     dead_band: 40,
+///     hysteresis: 16
+/// );
+///
+/// type MyPotentiometer<Input> = Potentiometer<Input, SomePotentiometerConfig>;
+///
+/// let mut potentiometer = MyPotentiometer::new(adc_channel);
+///
+/// loop {
+///     match potentiometer.update().unwrap() {
+///         PotentiometerEvent::NoChange => {}
+///         PotentiometerEvent::Changed(value) => do_something_upon_change(value),
+///     }
+/// }
+/// ```
+pub struct Potentiometer<Input: AnalogInput, Config: PotentiometerConfig> {
+    analog_input: Input,
+    filtered: u32,
+    samples: [u16; PotentiometerSmoothing::MAX_WINDOW as usize],
+    sample_index: usize,
+    value: u16,
+    config: PhantomData<Config>,
+}
+
+/// The event result of update [`Potentiometer`](crate::Potentiometer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PotentiometerEvent {
+    /// Quantized value isn't changed.
+    NoChange,
+    /// Quantized value is changed to the new value.
+    Changed(u16),
+}
+
+const FILTER_FRACTION_BITS: u32 = 8;
+
+impl<Input: AnalogInput, Config: PotentiometerConfig> Potentiometer<Input, Config> {
+    /// Creates a new [`Potentiometer<Input, Config>`] from a concrete `Input`.
+    ///
+    /// `analog_input` - an concrete instance of `Input`.
+    pub fn new(analog_input: Input) -> Self {
+        let raw = analog_input.read().unwrap_or(0);
+        let filtered = (raw as u32) << FILTER_FRACTION_BITS;
+
+        Potentiometer {
+            analog_input,
+            filtered,
+            samples: [raw; PotentiometerSmoothing::MAX_WINDOW as usize],
+            sample_index: 0,
+            value: Self::quantize(raw),
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Returns the quantized value in range `0..POSITIONS`.
+    pub fn value(&self) -> u16 {
+        self.value
+    }
+
+    /// Returns the smoothed raw value.
+    pub fn raw_value(&self) -> u16 {
+        (self.filtered >> FILTER_FRACTION_BITS) as u16
+    }
+
+    /// Borrow `Input`.
+    pub fn borrow_analog_input(&self) -> &Input {
+        &self.analog_input
+    }
+
+    /// Consumes `self` and release `Input`.
+    pub fn release_analog_input(self) -> Input {
+        self.analog_input
+    }
+
+    fn span() -> u32 {
+        (Config::MAX_VALUE as u32)
+            .saturating_sub(2 * Config::DEAD_BAND as u32)
+            .max(1)
+    }
+
+    fn travel(raw: u16) -> u32 {
+        (raw.saturating_sub(Config::DEAD_BAND) as u32).min(Self::span())
+    }
+
+    fn quantize(raw: u16) -> u16 {
+        let positions = Config::POSITIONS.max(1) as u32;
+        (Self::travel(raw) * positions / (Self::span() + 1)).min(positions - 1) as u16
+    }
+
+    fn position_bounds(value: u16) -> (u32, u32) {
+        let positions = Config::POSITIONS.max(1) as u32;
+        let value = value as u32;
+        (
+            value * (Self::span() + 1) / positions,
+            (value + 1) * (Self::span() + 1) / positions,
+        )
+    }
+}
+
+impl<Input: AnalogInput, Config: PotentiometerConfig> Control for Potentiometer<Input, Config> {
+    type Event = PotentiometerEvent;
+    type Error = Input::Error;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let sample = self.analog_input.read()?.min(Config::MAX_VALUE);
+        let raw = (sample as u32) << FILTER_FRACTION_BITS;
+
+        self.filtered = match Config::SMOOTHING {
+            PotentiometerSmoothing::None => raw,
+            PotentiometerSmoothing::Exponential(shift) => {
+                let shift = (shift as u32).min(u32::BITS - 1);
+                if raw >= self.filtered {
+                    self.filtered + ((raw - self.filtered) >> shift)
+                } else {
+                    self.filtered - ((self.filtered - raw) >> shift)
+                }
+            }
+            PotentiometerSmoothing::MovingAverage(window) => {
+                let window = window.clamp(1, PotentiometerSmoothing::MAX_WINDOW) as usize;
+                self.samples[self.sample_index % window] = sample;
+                self.sample_index = (self.sample_index + 1) % window;
+
+                let sum: u32 = self.samples[..window]
+                    .iter()
+                    .map(|sample| *sample as u32)
+                    .sum();
+                (sum << FILTER_FRACTION_BITS) / window as u32
+            }
+        };
+
+        let raw_value = self.raw_value();
+        let value = Self::quantize(raw_value);
+
+        if value == self.value {
+            return Ok(PotentiometerEvent::NoChange);
+        }
+
+        let travel = Self::travel(raw_value);
+        let (lower, upper) = Self::position_bounds(self.value);
+        let hysteresis = Config::HYSTERESIS as u32;
+
+        if travel + hysteresis < lower || travel >= upper + hysteresis {
+            self.value = value;
+            Ok(PotentiometerEvent::Changed(value))
+        } else {
+            Ok(PotentiometerEvent::NoChange)
+        }
+    }
+}
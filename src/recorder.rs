@@ -0,0 +1,309 @@
+use crate::{Control, DebouncedInputEvent, EncoderEvent};
+
+use core::marker::PhantomData;
+use num_traits::ToPrimitive;
+use timestamp_source::Timestamp;
+
+/// Represents a compact code of [`Control::Event`](crate::Control::Event) for [`Recorder`](crate::Recorder).
+pub trait EventCode: Sized {
+    /// Returns the code of the event.
+    fn event_code(&self) -> u8;
+
+    /// Returns the event from `code`, `None` if the code is unknown.
+    fn from_event_code(code: u8) -> Option<Self>;
+
+    /// Returns true if the event is a steady state that isn't worth to record, e.g. [`EncoderEvent::NoTurn`].
+    fn is_idle(&self) -> bool;
+}
+
+/// Represents a control that provides raw levels of inputs read by the last update.
+pub trait RawLevels {
+    /// Returns raw levels of inputs as bits, the first input is the least significant bit.
+    fn raw_levels(&self) -> u8;
+}
+
+/// The entry of [`Recorder`](crate::Recorder).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RecordEntry {
+    /// Ticks of timestamp since epoch, wrapped to `u32`.
+    pub ticks: u32,
+    /// Raw levels of inputs, see [`RawLevels`](crate::RawLevels), always 0 if raw levels aren't recorded.
+    pub raw_levels: u8,
+    /// The code of event, see [`EventCode`](crate::EventCode).
+    pub event_code: u8,
+}
+
+/// Concrete implementation of recorder of recent raw levels and events of a control.
+///
+/// An entry is recorded upon change of raw levels, non-idle event or error,
+/// the oldest entry is overwritten if the buffer is full.
+/// Raw levels are recorded only for controls that implement [`RawLevels`](crate::RawLevels),
+/// any other control is recorded by [`without_raw_levels`](crate::Recorder::without_raw_levels).
+///
+/// # Type Params
+/// `C` - [`Control`](crate::Control) that is recorded.
+///
+/// `TS` - [`Timestamp`](timestamp_source::Timestamp) that provides ticks of entries.
+///
+/// `N` - The number of entries in the ring buffer.
+///
+/// # Example
+/// ```ignore
+/// let mut recorder = Recorder::<_, MyTimestamp, 64>::new(MyDebouncedInput::new(pin.into_active_low_switch()));
+///
+/// loop {
+///     match recorder.update().unwrap() {
+///         DebouncedInputEvent::Rise => do_something_upon_rise(),
+///         _ => {}
+///     }
+///
+///     if crashed() {
+///         let mut buffer = [0; 64 * RecordEntry::SIZE];
+///         let len = recorder.encode(&mut buffer);
+///         save_crash_report(&buffer[..len]);
+///     }
+/// }
+/// ```
+pub struct Recorder<C: Control, TS: Timestamp, const N: usize> {
+    control: C,
+    entries: [RecordEntry; N],
+    head: usize,
+    len: usize,
+    raw_levels: Option<fn(&C) -> u8>,
+    last_raw_levels: Option<u8>,
+    timestamp: PhantomData<TS>,
+}
+
+impl RecordEntry {
+    /// The size of the encoded entry in bytes.
+    pub const SIZE: usize = 6;
+
+    /// The event code of an error of update.
+    pub const ERROR_EVENT_CODE: u8 = u8::MAX;
+
+    /// Encodes the entry as little-endian ticks, raw levels and event code.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let ticks = self.ticks.to_le_bytes();
+
+        [
+            ticks[0],
+            ticks[1],
+            ticks[2],
+            ticks[3],
+            self.raw_levels,
+            self.event_code,
+        ]
+    }
+
+    /// Decodes the entry encoded by [`to_bytes`](RecordEntry::to_bytes).
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        RecordEntry {
+            ticks: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            raw_levels: bytes[4],
+            event_code: bytes[5],
+        }
+    }
+
+    /// Returns true if the entry records an error of update.
+    pub fn is_error(&self) -> bool {
+        self.event_code == Self::ERROR_EVENT_CODE
+    }
+
+    /// Returns the recorded event, `None` if the code is unknown or the entry records an error.
+    pub fn event<E: EventCode>(&self) -> Option<E> {
+        E::from_event_code(self.event_code)
+    }
+}
+
+/// Decodes entries from `bytes` encoded by [`Recorder::encode`](crate::Recorder::encode),
+/// an incomplete trailing entry is ignored.
+pub fn decode_record_entries(bytes: &[u8]) -> impl Iterator<Item = RecordEntry> + '_ {
+    bytes.chunks_exact(RecordEntry::SIZE).map(|chunk| {
+        let mut entry = [0; RecordEntry::SIZE];
+        entry.copy_from_slice(chunk);
+        RecordEntry::from_bytes(&entry)
+    })
+}
+
+impl<C: Control + RawLevels, TS: Timestamp, const N: usize> Recorder<C, TS, N>
+where
+    C::Event: EventCode,
+    TS::Duration: ToPrimitive,
+{
+    /// Creates a new [`Recorder<C, TS, N>`] from a concrete `C` that records raw levels.
+    ///
+    /// `control` - an concrete instance of `C`.
+    pub fn new(control: C) -> Self {
+        Self::with_raw_levels(control, Some(C::raw_levels))
+    }
+}
+
+impl<C: Control, TS: Timestamp, const N: usize> Recorder<C, TS, N>
+where
+    C::Event: EventCode,
+    TS::Duration: ToPrimitive,
+{
+    /// Creates a new [`Recorder<C, TS, N>`] from a concrete `C` that records only events and errors.
+    ///
+    /// `control` - an concrete instance of `C`.
+    pub fn without_raw_levels(control: C) -> Self {
+        Self::with_raw_levels(control, None)
+    }
+
+    fn with_raw_levels(control: C, raw_levels: Option<fn(&C) -> u8>) -> Self {
+        Recorder {
+            control,
+            entries: [RecordEntry::default(); N],
+            head: 0,
+            len: 0,
+            raw_levels,
+            last_raw_levels: None,
+            timestamp: PhantomData::<TS>,
+        }
+    }
+
+    /// Returns the number of recorded entries.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no recorded entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all recorded entries.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.last_raw_levels = None;
+    }
+
+    /// Returns recorded entries from the oldest to the newest.
+    pub fn entries(&self) -> impl Iterator<Item = &RecordEntry> + '_ {
+        let start = (self.head + N - self.len) % N.max(1);
+
+        (0..self.len).map(move |index| &self.entries[(start + index) % N])
+    }
+
+    /// Encodes recorded entries from the oldest to the newest to `buffer`,
+    /// returns the number of written bytes, entries that don't fit are skipped.
+    pub fn encode(&self, buffer: &mut [u8]) -> usize {
+        let mut written = 0;
+
+        for (entry, chunk) in self
+            .entries()
+            .zip(buffer.chunks_exact_mut(RecordEntry::SIZE))
+        {
+            chunk.copy_from_slice(&entry.to_bytes());
+            written += RecordEntry::SIZE;
+        }
+
+        written
+    }
+
+    /// Borrow `C`.
+    pub fn borrow_control(&self) -> &C {
+        &self.control
+    }
+
+    /// Consumes `self` and release `C`.
+    pub fn release_control(self) -> C {
+        self.control
+    }
+
+    fn record(&mut self, raw_levels: u8, event_code: u8) {
+        if N == 0 {
+            return;
+        }
+
+        let ticks = TS::now()
+            .duration_since_epoch()
+            .to_u64()
+            .map_or(u32::MAX, |ticks| ticks as u32);
+
+        self.entries[self.head] = RecordEntry {
+            ticks,
+            raw_levels,
+            event_code,
+        };
+        self.head = (self.head + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+}
+
+impl<C: Control, TS: Timestamp, const N: usize> Control for Recorder<C, TS, N>
+where
+    C::Event: EventCode,
+    TS::Duration: ToPrimitive,
+{
+    type Event = C::Event;
+    type Error = C::Error;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let result = self.control.update();
+        let raw_levels = self
+            .raw_levels
+            .map_or(0, |raw_levels| raw_levels(&self.control));
+        let raw_levels_changed = self.last_raw_levels != Some(raw_levels);
+        self.last_raw_levels = Some(raw_levels);
+
+        match &result {
+            Ok(event) if raw_levels_changed || !event.is_idle() => {
+                self.record(raw_levels, event.event_code())
+            }
+            Ok(_) => {}
+            Err(_) => self.record(raw_levels, RecordEntry::ERROR_EVENT_CODE),
+        }
+
+        result
+    }
+}
+
+impl EventCode for DebouncedInputEvent {
+    fn event_code(&self) -> u8 {
+        match self {
+            DebouncedInputEvent::Low => 0,
+            DebouncedInputEvent::High => 1,
+            DebouncedInputEvent::Rise => 2,
+            DebouncedInputEvent::Fall => 3,
+        }
+    }
+
+    fn from_event_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(DebouncedInputEvent::Low),
+            1 => Some(DebouncedInputEvent::High),
+            2 => Some(DebouncedInputEvent::Rise),
+            3 => Some(DebouncedInputEvent::Fall),
+            _ => None,
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        matches!(self, DebouncedInputEvent::Low | DebouncedInputEvent::High)
+    }
+}
+
+impl EventCode for EncoderEvent {
+    fn event_code(&self) -> u8 {
+        match self {
+            EncoderEvent::NoTurn => 0,
+            EncoderEvent::ClockwiseTurn => 1,
+            EncoderEvent::CounterClockwiseTurn => 2,
+        }
+    }
+
+    fn from_event_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(EncoderEvent::NoTurn),
+            1 => Some(EncoderEvent::ClockwiseTurn),
+            2 => Some(EncoderEvent::CounterClockwiseTurn),
+            _ => None,
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        *self == EncoderEvent::NoTurn
+    }
+}
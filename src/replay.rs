@@ -0,0 +1,430 @@
+//! Replay of captured pin traces through controls, available with `replay` feature.
+//!
+//! Traces are read from VCD or CSV files of logic analyzers, time is measured in nanoseconds.
+//! Timers of replay configs are configured at runtime by [`ReplayTimer::set_duration`].
+//!
+//! # Example
+//! ```ignore
+//! let trace = Trace::parse_vcd(&std::fs::read_to_string("button.vcd")?)?;
+//!
+//! ReplayTimer::set_duration(parse_duration("20ms").unwrap());
+//!
+//! for (ticks, event) in replay_debounced_input(trace.waveform("btn")?, 1_000_000, trace.end())? {
+//!     println!("{} {:?}", format_ticks(ticks), event);
+//! }
+//! ```
+
+use crate::{
+    testing::{ManualClock, ManualTimestamp, ScriptedInputSwitch},
+    Control, DebouncedInput, DebouncedInputConfig, DebouncedInputEvent, Encoder, EncoderConfig,
+    EncoderEvent, EventCode, RecordEntry,
+};
+
+use core::{
+    cell::Cell,
+    fmt::{Debug, Display, Formatter},
+};
+use std::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+std::thread_local! {
+    static REPLAY_TIMER_DURATION: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Errors container of replay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// Error of trace parsing at line
+    Parse(usize, String),
+    /// The signal isn't found in the trace
+    UnknownSignal(String),
+    /// The counts div isn't supported by replay configs
+    UnsupportedCountsDiv(u32),
+    /// Error of the replayed control update
+    Control(String),
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReplayError::Parse(line, message) => write!(f, "line {}: {}", line, message),
+            ReplayError::UnknownSignal(name) => write!(f, "unknown signal: {}", name),
+            ReplayError::UnsupportedCountsDiv(counts_div) => {
+                write!(f, "unsupported counts div: {}, use 1, 2 or 4", counts_div)
+            }
+            ReplayError::Control(message) => write!(f, "control error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Elapsed timer with duration that is configured at runtime, the duration is thread-local.
+pub struct ReplayTimer;
+
+impl ReplayTimer {
+    /// Sets the duration of all replay timers in nanoseconds.
+    pub fn set_duration(duration: u64) {
+        REPLAY_TIMER_DURATION.with(|timer_duration| timer_duration.set(duration));
+    }
+
+    /// Returns the duration of all replay timers in nanoseconds.
+    pub fn duration() -> u64 {
+        REPLAY_TIMER_DURATION.with(Cell::get)
+    }
+}
+
+impl ElapsedTimer for ReplayTimer {
+    type Timestamp = ManualTimestamp;
+
+    fn timeout(
+        &self,
+        from: &Self::Timestamp,
+        to: &Self::Timestamp,
+    ) -> Result<bool, <Self::Timestamp as Timestamp>::Error> {
+        Ok(to.duration_since(from)? >= Self::duration())
+    }
+}
+
+/// Config of [`DebouncedInput`](crate::DebouncedInput) for replay.
+pub struct ReplayDebouncedInputConfig;
+
+impl DebouncedInputConfig for ReplayDebouncedInputConfig {
+    type Timer = ReplayTimer;
+    const DEBOUNCE_TIMER: ReplayTimer = ReplayTimer;
+}
+
+macro_rules! replay_encoder_config {
+    ($config_name:ident, $counts_div:expr) => {
+        #[doc = concat!("Config of [`Encoder`](crate::Encoder) for replay with counts div ", stringify!($counts_div), ".")]
+        pub struct $config_name;
+
+        impl DebouncedInputConfig for $config_name {
+            type Timer = ReplayTimer;
+            const DEBOUNCE_TIMER: ReplayTimer = ReplayTimer;
+        }
+
+        impl EncoderConfig for $config_name {
+            type Counts = i8;
+            const COUNTS_DIV: i8 = $counts_div;
+        }
+    };
+}
+
+replay_encoder_config!(ReplayEncoderConfig1, 1);
+replay_encoder_config!(ReplayEncoderConfig2, 2);
+replay_encoder_config!(ReplayEncoderConfig4, 4);
+
+/// Captured trace of digital signals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    names: Vec<String>,
+    waveforms: Vec<Vec<(u64, bool)>>,
+}
+
+impl Trace {
+    /// Parses a VCD trace, only scalar signals are used, `x` and `z` values are treated as low.
+    /// # Errors
+    /// This function will return an error if the trace is malformed.
+    pub fn parse_vcd(source: &str) -> Result<Self, ReplayError> {
+        let mut trace = Trace::default();
+        let mut ids: Vec<String> = Vec::new();
+        let mut timescale = 1.0;
+        let mut ticks = 0;
+
+        let mut tokens = source
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| line.split_whitespace().map(move |token| (index + 1, token)));
+
+        while let Some((line, token)) = tokens.next() {
+            let error = |message: &str| ReplayError::Parse(line, message.to_string());
+
+            match token {
+                "$timescale" => {
+                    let value: String = tokens
+                        .by_ref()
+                        .map(|(_, token)| token)
+                        .take_while(|token| *token != "$end")
+                        .collect();
+                    timescale =
+                        parse_timescale(&value).ok_or_else(|| error("invalid timescale"))?;
+                }
+                "$var" => {
+                    let fields: Vec<&str> = tokens
+                        .by_ref()
+                        .map(|(_, token)| token)
+                        .take_while(|token| *token != "$end")
+                        .collect();
+                    if fields.len() < 4 {
+                        return Err(error("invalid variable"));
+                    }
+                    if fields[1] == "1" {
+                        ids.push(fields[2].to_string());
+                        trace.names.push(fields[3].to_string());
+                        trace.waveforms.push(Vec::new());
+                    }
+                }
+                "$dumpvars" | "$dumpon" | "$dumpoff" | "$dumpall" | "$end" => {}
+                _ if token.starts_with('$') => {
+                    tokens.by_ref().find(|(_, token)| *token == "$end");
+                }
+                _ if token.starts_with('#') => {
+                    let time: u64 = token[1..].parse().map_err(|_| error("invalid time"))?;
+                    ticks = (time as f64 * timescale) as u64;
+                }
+                _ if token.starts_with(['b', 'B', 'r', 'R']) => {
+                    tokens.next();
+                }
+                _ => {
+                    let (value, id) = token.split_at(1);
+                    let level = match value {
+                        "1" => true,
+                        "0" | "x" | "X" | "z" | "Z" => false,
+                        _ => return Err(error("invalid value change")),
+                    };
+                    if let Some(index) = ids.iter().position(|known| known == id) {
+                        trace.push(index, ticks, level);
+                    }
+                }
+            }
+        }
+
+        Ok(trace)
+    }
+
+    /// Parses a CSV trace with header, the first column is time in seconds, other columns are levels `0` or `1`.
+    /// # Errors
+    /// This function will return an error if the trace is malformed.
+    pub fn parse_csv(source: &str) -> Result<Self, ReplayError> {
+        let mut trace = Trace::default();
+        let mut lines = source
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let (_, header) = lines
+            .next()
+            .ok_or_else(|| ReplayError::Parse(1, "missing header".to_string()))?;
+        for name in header.split(',').skip(1) {
+            trace.names.push(name.trim().to_string());
+            trace.waveforms.push(Vec::new());
+        }
+
+        for (index, line) in lines {
+            let error = |message: &str| ReplayError::Parse(index + 1, message.to_string());
+            let mut columns = line.split(',').map(str::trim);
+
+            let seconds: f64 = columns
+                .next()
+                .and_then(|column| column.parse().ok())
+                .ok_or_else(|| error("invalid time"))?;
+            let ticks = (seconds * 1e9).round() as u64;
+
+            for signal in 0..trace.names.len() {
+                let level = match columns.next() {
+                    Some("1") => true,
+                    Some("0") => false,
+                    _ => return Err(error("invalid level")),
+                };
+                trace.push(signal, ticks, level);
+            }
+        }
+
+        Ok(trace)
+    }
+
+    /// Creates a trace from entries of [`Recorder`](crate::Recorder), `names` are names of raw level bits
+    /// from the least significant bit, `tick` is the duration of the recorder tick in nanoseconds.
+    ///
+    /// Wrapped ticks of entries are unwrapped, entries of errors keep the previous levels.
+    pub fn from_record_entries(
+        entries: impl IntoIterator<Item = RecordEntry>,
+        names: &[&str],
+        tick: u64,
+    ) -> Self {
+        let mut trace = Trace {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            waveforms: std::vec![Vec::new(); names.len()],
+        };
+        let mut last_ticks: Option<u32> = None;
+        let mut wraps = 0u64;
+
+        for entry in entries {
+            if last_ticks.is_some_and(|last_ticks| entry.ticks < last_ticks) {
+                wraps += 1;
+            }
+            last_ticks = Some(entry.ticks);
+
+            if entry.is_error() {
+                continue;
+            }
+
+            let ticks = ((wraps << u32::BITS) + entry.ticks as u64) * tick;
+            for signal in 0..names.len() {
+                trace.push(signal, ticks, entry.raw_levels & 1 << signal != 0);
+            }
+        }
+
+        trace
+    }
+
+    /// Returns names of signals.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the waveform of the signal with `name`.
+    /// # Errors
+    /// This function will return an error if the signal isn't found.
+    pub fn waveform(&self, name: &str) -> Result<&[(u64, bool)], ReplayError> {
+        self.names
+            .iter()
+            .position(|known| known == name)
+            .map(|index| self.waveforms[index].as_slice())
+            .ok_or_else(|| ReplayError::UnknownSignal(name.to_string()))
+    }
+
+    /// Returns the time of the last change in nanoseconds.
+    pub fn end(&self) -> u64 {
+        self.waveforms
+            .iter()
+            .filter_map(|waveform| waveform.last().map(|(ticks, _)| *ticks))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn push(&mut self, signal: usize, ticks: u64, level: bool) {
+        let waveform = &mut self.waveforms[signal];
+
+        match waveform.last_mut() {
+            Some((last_ticks, last_level)) if *last_ticks == ticks => *last_level = level,
+            Some((_, last_level)) if *last_level == level => {}
+            _ => waveform.push((ticks, level)),
+        }
+    }
+}
+
+/// Parses a duration with suffix `ns`, `us`, `ms` or `s` to nanoseconds, e.g. `20ms`.
+pub fn parse_duration(duration: &str) -> Option<u64> {
+    let duration = duration.trim();
+    let split = duration
+        .find(|symbol: char| !(symbol.is_ascii_digit() || symbol == '.'))
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    Some((value * unit_to_nanos(unit.trim())?).round() as u64)
+}
+
+/// Formats nanoseconds as seconds with nanosecond precision.
+pub fn format_ticks(ticks: u64) -> String {
+    format!("{}.{:09}", ticks / 1_000_000_000, ticks % 1_000_000_000)
+}
+
+/// Replays `waveform` through [`DebouncedInput`](crate::DebouncedInput) updated every `period` nanoseconds
+/// until `until` nanoseconds, returns edges with time.
+/// # Errors
+/// This function will return an error if an update of [`DebouncedInput`](crate::DebouncedInput) is failed.
+pub fn replay_debounced_input(
+    waveform: &[(u64, bool)],
+    period: u64,
+    until: u64,
+) -> Result<Vec<(u64, DebouncedInputEvent)>, ReplayError> {
+    ManualClock::reset();
+
+    let input_switch = ScriptedInputSwitch::new(waveform.iter().copied());
+    let mut debounced_input = DebouncedInput::<_, ReplayDebouncedInputConfig>::new(input_switch);
+
+    replay(&mut debounced_input, period, until)
+}
+
+/// Replays `waveform_a` and `waveform_b` through [`Encoder`](crate::Encoder) with `counts_div`
+/// updated every `period` nanoseconds until `until` nanoseconds, returns turns with time.
+/// # Errors
+/// This function will return an error if `counts_div` isn't 1, 2 or 4
+/// or an update of [`Encoder`](crate::Encoder) is failed.
+pub fn replay_encoder(
+    waveform_a: &[(u64, bool)],
+    waveform_b: &[(u64, bool)],
+    counts_div: u32,
+    period: u64,
+    until: u64,
+) -> Result<Vec<(u64, EncoderEvent)>, ReplayError> {
+    fn replay_with<Config: EncoderConfig<Timer = ReplayTimer>>(
+        waveform_a: &[(u64, bool)],
+        waveform_b: &[(u64, bool)],
+        period: u64,
+        until: u64,
+    ) -> Result<Vec<(u64, EncoderEvent)>, ReplayError> {
+        ManualClock::reset();
+
+        let mut encoder = Encoder::<_, _, Config>::new(
+            ScriptedInputSwitch::new(waveform_a.iter().copied()),
+            ScriptedInputSwitch::new(waveform_b.iter().copied()),
+        );
+
+        replay(&mut encoder, period, until)
+    }
+
+    match counts_div {
+        1 => replay_with::<ReplayEncoderConfig1>(waveform_a, waveform_b, period, until),
+        2 => replay_with::<ReplayEncoderConfig2>(waveform_a, waveform_b, period, until),
+        4 => replay_with::<ReplayEncoderConfig4>(waveform_a, waveform_b, period, until),
+        _ => Err(ReplayError::UnsupportedCountsDiv(counts_div)),
+    }
+}
+
+/// Updates `control` every `period` ticks of [`ManualClock`](crate::testing::ManualClock) until `until` ticks,
+/// only non-idle events are kept, so memory doesn't grow with the length of the trace.
+fn replay<C: Control>(
+    control: &mut C,
+    period: u64,
+    until: u64,
+) -> Result<Vec<(u64, C::Event)>, ReplayError>
+where
+    C::Event: EventCode,
+    C::Error: Debug,
+{
+    let mut events = Vec::new();
+
+    while ManualClock::ticks() < until {
+        let event = control
+            .update()
+            .map_err(|error| ReplayError::Control(format!("{:?}", error)))?;
+
+        if !event.is_idle() {
+            events.push((ManualClock::ticks(), event));
+        }
+
+        ManualClock::advance(period);
+    }
+
+    Ok(events)
+}
+
+fn parse_timescale(timescale: &str) -> Option<f64> {
+    let split = timescale
+        .find(|symbol: char| !symbol.is_ascii_digit())
+        .unwrap_or(timescale.len());
+    let (value, unit) = timescale.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    Some(value * unit_to_nanos(unit)?)
+}
+
+fn unit_to_nanos(unit: &str) -> Option<f64> {
+    match unit {
+        "s" => Some(1e9),
+        "ms" => Some(1e6),
+        "us" => Some(1e3),
+        "ns" => Some(1.0),
+        "ps" => Some(1e-3),
+        "fs" => Some(1e-6),
+        _ => None,
+    }
+}
@@ -0,0 +1,167 @@
+use core::marker::PhantomData;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`SequenceMatcher`](crate::SequenceMatcher).
+pub trait SequenceMatcherConfig {
+    /// The type of events that form sequences, e.g. [`Control::Event`](crate::Control::Event).
+    type Event: Copy + PartialEq + 'static;
+
+    /// Elapsed timer type that used for [`SequenceMatcher`](crate::SequenceMatcher).
+    type Timer: ElapsedTimer;
+
+    /// Elapsed timer instance that used for [`SequenceMatcher`](crate::SequenceMatcher).
+    /// The collected sequence is reset if this timer is elapsed between two events.
+    const TIMEOUT_TIMER: Self::Timer;
+
+    /// Registered patterns, the id of a pattern is its index.
+    const PATTERNS: &'static [&'static [Self::Event]];
+
+    /// Events that are skipped by matcher, e.g. [`EncoderEvent::NoTurn`](crate::EncoderEvent::NoTurn).
+    const IDLE_EVENTS: &'static [Self::Event] = &[];
+}
+
+/// Concrete implementation of key sequence (secret code) matcher.
+///
+/// # Type Params
+/// `Config` - [`SequenceMatcherConfig`](crate::SequenceMatcherConfig) that provides configs for matcher.
+///
+/// `LEN` - The maximum length of sequence, must be not less than the longest registered pattern.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Key {
+///     Up,
+///     Down,
+///     Ok,
+/// }
+///
+/// sequence_matcher_config!(
+///     SomeSequenceMatcherConfig,
+///     event: Key,
+///     timeout_timer: MyElapsedTimer = MyElapsedTimer::new(2.secs()),
+///     patterns: &[&[Key::Up, Key::Up, Key::Down, Key::Down, Key::Ok]]
+/// );
+///
+/// let mut sequence_matcher = SequenceMatcher::<SomeSequenceMatcherConfig, 5>::new();
+///
+/// loop {
+///     if let Some(key) = read_key() {
+///         match sequence_matcher.push(key).unwrap() {
+///             SequenceMatcherEvent::NoMatch => {}
+///             SequenceMatcherEvent::SequenceMatched(0) => unlock_service_mode(),
+///             SequenceMatcherEvent::SequenceMatched(_) => {}
+///         }
+///     }
+/// }
+/// ```
+pub struct SequenceMatcher<Config: SequenceMatcherConfig, const LEN: usize> {
+    events: [Option<Config::Event>; LEN],
+    len: usize,
+    last_event: Option<<Config::Timer as ElapsedTimer>::Timestamp>,
+    config: PhantomData<Config>,
+}
+
+/// The event result of push to [`SequenceMatcher`](crate::SequenceMatcher).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceMatcherEvent {
+    /// Collected sequence doesn't match any pattern.
+    NoMatch,
+    /// Collected sequence matches a pattern with id.
+    SequenceMatched(usize),
+}
+
+impl<Config: SequenceMatcherConfig, const LEN: usize> SequenceMatcher<Config, LEN> {
+    /// Creates a new [`SequenceMatcher<Config, LEN>`].
+    ///
+    /// # Panics
+    /// If any pattern is longer than `LEN`.
+    pub fn new() -> Self {
+        assert!(Config::PATTERNS.iter().all(|pattern| pattern.len() <= LEN));
+
+        SequenceMatcher {
+            events: [None; LEN],
+            len: 0,
+            last_event: None,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Returns the length of the collected sequence.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the collected sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Resets the collected sequence.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.last_event = None;
+    }
+
+    /// Pushes an event to the collected sequence and checks registered patterns.
+    ///
+    /// The collected sequence is reset if it's matched
+    /// or if [`TIMEOUT_TIMER`](crate::SequenceMatcherConfig::TIMEOUT_TIMER) is elapsed since the previous event.
+    /// # Errors
+    /// This function will return an error if [`ElapsedTimer::timeout`] returns an error.
+    pub fn push(
+        &mut self,
+        event: Config::Event,
+    ) -> Result<
+        SequenceMatcherEvent,
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+    > {
+        if Config::IDLE_EVENTS.contains(&event) {
+            return Ok(SequenceMatcherEvent::NoMatch);
+        }
+
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        if let Some(last_event) = &self.last_event {
+            if Config::TIMEOUT_TIMER.timeout(last_event, &now)? {
+                self.len = 0;
+            }
+        }
+        self.last_event = Some(now);
+
+        if LEN == 0 {
+            return Ok(SequenceMatcherEvent::NoMatch);
+        }
+
+        if self.len == LEN {
+            self.events.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.events[self.len] = Some(event);
+        self.len += 1;
+
+        let events = &self.events[..self.len];
+        let matched = Config::PATTERNS.iter().position(|pattern| {
+            !pattern.is_empty()
+                && pattern.len() <= events.len()
+                && events[events.len() - pattern.len()..]
+                    .iter()
+                    .zip(pattern.iter())
+                    .all(|(event, pattern_event)| *event == Some(*pattern_event))
+        });
+
+        Ok(match matched {
+            Some(id) => {
+                self.reset();
+                SequenceMatcherEvent::SequenceMatched(id)
+            }
+            None => SequenceMatcherEvent::NoMatch,
+        })
+    }
+}
+
+impl<Config: SequenceMatcherConfig, const LEN: usize> Default for SequenceMatcher<Config, LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
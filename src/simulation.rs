@@ -0,0 +1,302 @@
+//! Simulation of real contacts on a host to validate configs of controls before hardware exists,
+//! available with `simulation` feature.
+//!
+//! Waveforms are generated in ticks of [`ManualClock`](crate::testing::ManualClock) and can be played by
+//! [`ScriptedInputSwitch`](crate::testing::ScriptedInputSwitch).
+//!
+//! # Example
+//! ```ignore
+//! debounced_input_config!(
+//!     SomeDebouncedInputConfig,
+//!     debounce_timer: Timer<ManualTimestamp> = Timer::new(20)
+//! );
+//!
+//! let mut rng = XorShiftRng::new(42);
+//! let bounce = BounceProfile { bounces: 5, duration: 10, jitter: 2 };
+//! let edges = [(100, true), (300, false)];
+//!
+//! ManualClock::reset();
+//!
+//! let input_switch = ScriptedInputSwitch::new(bouncing_waveform(&edges, &bounce, &mut rng));
+//! let mut debounced_input = DebouncedInput::<_, SomeDebouncedInputConfig>::new(input_switch);
+//!
+//! let detected = simulate(&mut debounced_input, 500, 1).unwrap();
+//! let report = compare_events(&debounced_input_events(&edges), &detected, &[Low, High], 50);
+//!
+//! assert!(report.is_clean(), "{:?}", report);
+//! ```
+
+use crate::{testing::ManualClock, Control, DebouncedInputEvent, EncoderEvent};
+
+use std::vec::Vec;
+
+/// Seeded xorshift pseudo random generator, simulations with the same seed are reproducible.
+#[derive(Debug, Clone)]
+pub struct XorShiftRng {
+    state: u64,
+}
+
+impl XorShiftRng {
+    /// Creates a new [`XorShiftRng`] from `seed`.
+    pub fn new(seed: u64) -> Self {
+        XorShiftRng {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    /// Returns the next pseudo random value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns the next pseudo random value in `0..=max`.
+    pub fn up_to(&mut self, max: u64) -> u64 {
+        match max.checked_add(1) {
+            Some(bound) => self.next_u64() % bound,
+            None => self.next_u64(),
+        }
+    }
+}
+
+/// The profile of contact bounce after each edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BounceProfile {
+    /// The number of bounces, each bounce is a pair of short opposite and original levels.
+    pub bounces: u32,
+    /// The duration of bouncing in ticks after the edge.
+    pub duration: u64,
+    /// The maximum random delay of the edge in ticks.
+    pub jitter: u64,
+}
+
+impl BounceProfile {
+    /// The profile of ideal contact without bounce.
+    pub const CLEAN: BounceProfile = BounceProfile {
+        bounces: 0,
+        duration: 0,
+        jitter: 0,
+    };
+}
+
+/// A segment of encoder rotation with constant speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadratureSegment {
+    /// The number of quadrature steps, positive is clockwise and negative is counter clockwise.
+    pub steps: i64,
+    /// The period of one quadrature step in ticks.
+    pub period: u64,
+}
+
+/// Generated quadrature waveforms and expected events of [`Encoder`](crate::Encoder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quadrature {
+    /// Waveform of channel A.
+    pub a: Vec<(u64, bool)>,
+    /// Waveform of channel B.
+    pub b: Vec<(u64, bool)>,
+    /// Expected events at the time of the clean steps.
+    pub expected: Vec<(u64, EncoderEvent)>,
+}
+
+/// The result of comparison of expected and detected events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationReport<E> {
+    /// The number of matched events.
+    pub matched: usize,
+    /// Expected events that weren't detected in the window.
+    pub missed: Vec<(u64, E)>,
+    /// Detected events that weren't expected.
+    pub extra: Vec<(u64, E)>,
+    /// The maximum delay between expected and detected events in ticks.
+    pub max_latency: u64,
+}
+
+impl<E> SimulationReport<E> {
+    /// Returns true if there are no missed and extra events.
+    pub fn is_clean(&self) -> bool {
+        self.missed.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Generates a bouncing waveform from clean `edges` of `(ticks, level)`.
+///
+/// Each edge is delayed by random jitter and followed by random bounces within the bounce duration,
+/// the duration should be less than intervals between edges.
+pub fn bouncing_waveform(
+    edges: &[(u64, bool)],
+    profile: &BounceProfile,
+    rng: &mut XorShiftRng,
+) -> Vec<(u64, bool)> {
+    let mut waveform = Vec::new();
+
+    for &(ticks, level) in edges {
+        let start = ticks + rng.up_to(profile.jitter);
+        waveform.push((start, level));
+
+        let mut toggles: Vec<u64> = (0..profile.bounces * 2)
+            .map(|_| start + 1 + rng.up_to(profile.duration.saturating_sub(1)))
+            .collect();
+        toggles.sort_unstable();
+
+        for (index, toggle) in toggles.into_iter().enumerate() {
+            waveform.push((toggle, (index % 2 == 0) != level));
+        }
+    }
+
+    waveform
+}
+
+/// Generates quadrature waveforms of encoder rotation by `segments` from `start` ticks,
+/// each channel edge is bounced by `noise`.
+///
+/// `counts_div` - the same value as [`EncoderConfig::COUNTS_DIV`](crate::EncoderConfig::COUNTS_DIV).
+pub fn quadrature_waveforms(
+    start: u64,
+    segments: &[QuadratureSegment],
+    counts_div: u32,
+    noise: &BounceProfile,
+    rng: &mut XorShiftRng,
+) -> Quadrature {
+    const STATES: [(bool, bool); 4] = [(false, false), (true, false), (true, true), (false, true)];
+
+    let mut edges_a = Vec::new();
+    let mut edges_b = Vec::new();
+    let mut expected = Vec::new();
+
+    let mut ticks = start;
+    let mut phase = 0usize;
+    let mut counts = 0i64;
+
+    for segment in segments {
+        let direct: i64 = if segment.steps.is_negative() { -1 } else { 1 };
+
+        for _ in 0..segment.steps.unsigned_abs() {
+            ticks += segment.period;
+
+            let (a, b) = STATES[phase];
+            phase = (phase as i64 + direct).rem_euclid(4) as usize;
+            let (next_a, next_b) = STATES[phase];
+
+            if a != next_a {
+                edges_a.push((ticks, next_a));
+            }
+            if b != next_b {
+                edges_b.push((ticks, next_b));
+            }
+
+            counts += direct;
+            if counts != 0 && counts % counts_div as i64 == 0 {
+                expected.push((
+                    ticks,
+                    match counts.is_positive() {
+                        true => EncoderEvent::ClockwiseTurn,
+                        false => EncoderEvent::CounterClockwiseTurn,
+                    },
+                ));
+                counts = 0;
+            }
+        }
+    }
+
+    Quadrature {
+        a: bouncing_waveform(&edges_a, noise, rng),
+        b: bouncing_waveform(&edges_b, noise, rng),
+        expected,
+    }
+}
+
+/// Returns expected events of [`DebouncedInput`](crate::DebouncedInput) for clean `edges`,
+/// the initial level is low.
+pub fn debounced_input_events(edges: &[(u64, bool)]) -> Vec<(u64, DebouncedInputEvent)> {
+    let mut level = false;
+
+    edges
+        .iter()
+        .filter_map(|&(ticks, edge_level)| {
+            if edge_level == level {
+                return None;
+            }
+            level = edge_level;
+
+            Some((
+                ticks,
+                match level {
+                    true => DebouncedInputEvent::Rise,
+                    false => DebouncedInputEvent::Fall,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Updates `control` every `step` ticks of [`ManualClock`](crate::testing::ManualClock) until `until` ticks,
+/// returns the detected events with ticks.
+/// # Errors
+/// This function will return the first error of [`Control::update`].
+pub fn simulate<C: Control>(
+    control: &mut C,
+    until: u64,
+    step: u64,
+) -> Result<Vec<(u64, C::Event)>, C::Error> {
+    let mut events = Vec::new();
+
+    while ManualClock::ticks() < until {
+        events.push((ManualClock::ticks(), control.update()?));
+        ManualClock::advance(step);
+    }
+
+    Ok(events)
+}
+
+/// Compares `expected` and `detected` events skipping `idle` detected events,
+/// an expected event is matched by the first equal detected event within `window` ticks after it.
+pub fn compare_events<E: Copy + PartialEq>(
+    expected: &[(u64, E)],
+    detected: &[(u64, E)],
+    idle: &[E],
+    window: u64,
+) -> SimulationReport<E> {
+    let detected: Vec<_> = detected
+        .iter()
+        .filter(|(_, event)| !idle.contains(event))
+        .copied()
+        .collect();
+    let mut used = std::vec![false; detected.len()];
+
+    let mut report = SimulationReport {
+        matched: 0,
+        missed: Vec::new(),
+        extra: Vec::new(),
+        max_latency: 0,
+    };
+
+    for &(ticks, event) in expected {
+        let found = detected.iter().enumerate().position(|(index, (at, e))| {
+            !used[index] && *e == event && *at >= ticks && *at - ticks <= window
+        });
+
+        match found {
+            Some(index) => {
+                used[index] = true;
+                report.matched += 1;
+                report.max_latency = report.max_latency.max(detected[index].0 - ticks);
+            }
+            None => report.missed.push((ticks, event)),
+        }
+    }
+
+    report.extra = detected
+        .into_iter()
+        .zip(used)
+        .filter_map(|(event, used)| (!used).then_some(event))
+        .collect();
+
+    report
+}
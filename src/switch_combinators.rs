@@ -0,0 +1,130 @@
+use crate::CombinedSwitchError;
+
+use switch_hal::InputSwitch;
+
+/// [`InputSwitch`](switch_hal::InputSwitch) that is active when the inner switch is inactive.
+///
+/// # Example
+/// ```ignore
+/// let mut debounced_input = MyDebouncedInput::new(Inverted::new(reed_pin.into_active_high_switch()));
+/// ```
+pub struct Inverted<Switch: InputSwitch> {
+    input_switch: Switch,
+}
+
+/// [`InputSwitch`](switch_hal::InputSwitch) that is active when any of two switches is active.
+///
+/// Both switches are always read, so errors are propagated regardless of states.
+///
+/// # Example
+/// ```ignore
+/// let door_open = AnyOf::new(
+///     door_a_pin.into_active_low_switch(),
+///     door_b_pin.into_active_low_switch(),
+/// );
+/// let mut debounced_input = MyDebouncedInput::new(door_open);
+/// ```
+pub struct AnyOf<SwitchA: InputSwitch, SwitchB: InputSwitch> {
+    input_switch_a: SwitchA,
+    input_switch_b: SwitchB,
+}
+
+/// [`InputSwitch`](switch_hal::InputSwitch) that is active when both switches are active.
+///
+/// Both switches are always read, so errors are propagated regardless of states.
+///
+/// # Example
+/// ```ignore
+/// let armed = AllOf::new(key_pin.into_active_low_switch(), guard_pin.into_active_low_switch());
+/// let mut debounced_input = MyDebouncedInput::new(armed);
+/// ```
+pub struct AllOf<SwitchA: InputSwitch, SwitchB: InputSwitch> {
+    input_switch_a: SwitchA,
+    input_switch_b: SwitchB,
+}
+
+/// [`InputSwitch`](switch_hal::InputSwitch) that is active when exactly one of two switches is active.
+///
+/// Both switches are always read, so errors are propagated regardless of states.
+///
+/// # Example
+/// ```ignore
+/// let toggled = Xor::new(
+///     stair_top_pin.into_active_low_switch(),
+///     stair_bottom_pin.into_active_low_switch(),
+/// );
+/// let mut debounced_input = MyDebouncedInput::new(toggled);
+/// ```
+pub struct Xor<SwitchA: InputSwitch, SwitchB: InputSwitch> {
+    input_switch_a: SwitchA,
+    input_switch_b: SwitchB,
+}
+
+impl<Switch: InputSwitch> Inverted<Switch> {
+    /// Creates a new [`Inverted<Switch>`] from a concrete `Switch`.
+    pub fn new(input_switch: Switch) -> Self {
+        Inverted { input_switch }
+    }
+
+    /// Borrow `Switch`.
+    pub fn borrow_input_switch(&self) -> &Switch {
+        &self.input_switch
+    }
+
+    /// Consumes `self` and release `Switch`.
+    pub fn release_input_switch(self) -> Switch {
+        self.input_switch
+    }
+}
+
+impl<Switch: InputSwitch> InputSwitch for Inverted<Switch> {
+    type Error = Switch::Error;
+
+    fn is_active(&self) -> Result<bool, Self::Error> {
+        Ok(!self.input_switch.is_active()?)
+    }
+}
+
+macro_rules! impl_binary_combinator {
+    ($name:ident, |$a:ident, $b:ident| $combine:expr) => {
+        impl<SwitchA: InputSwitch, SwitchB: InputSwitch> $name<SwitchA, SwitchB> {
+            #[doc = concat!("Creates a new [`", stringify!($name), "<SwitchA, SwitchB>`] from concretes `SwitchA`, `SwitchB`.")]
+            pub fn new(input_switch_a: SwitchA, input_switch_b: SwitchB) -> Self {
+                $name {
+                    input_switch_a,
+                    input_switch_b,
+                }
+            }
+
+            /// Borrow `(SwitchA, SwitchB)`.
+            pub fn borrow_input_switches(&self) -> (&SwitchA, &SwitchB) {
+                (&self.input_switch_a, &self.input_switch_b)
+            }
+
+            /// Consumes `self` and release `(SwitchA, SwitchB)`.
+            pub fn release_input_switches(self) -> (SwitchA, SwitchB) {
+                (self.input_switch_a, self.input_switch_b)
+            }
+        }
+
+        impl<SwitchA: InputSwitch, SwitchB: InputSwitch> InputSwitch for $name<SwitchA, SwitchB> {
+            type Error = CombinedSwitchError<SwitchA::Error, SwitchB::Error>;
+
+            fn is_active(&self) -> Result<bool, Self::Error> {
+                let result_a = self.input_switch_a.is_active();
+                let result_b = self.input_switch_b.is_active();
+
+                match (result_a, result_b) {
+                    (Ok($a), Ok($b)) => Ok($combine),
+                    (Err(error_a), Ok(_)) => Err(CombinedSwitchError::A(error_a)),
+                    (Ok(_), Err(error_b)) => Err(CombinedSwitchError::B(error_b)),
+                    (Err(error_a), Err(error_b)) => Err(CombinedSwitchError::Both(error_a, error_b)),
+                }
+            }
+        }
+    };
+}
+
+impl_binary_combinator!(AnyOf, |a, b| a || b);
+impl_binary_combinator!(AllOf, |a, b| a && b);
+impl_binary_combinator!(Xor, |a, b| a != b);
@@ -0,0 +1,180 @@
+//! Helpers for testing of controls on a host, available with `testing` feature.
+//!
+//! # Example
+//! ```ignore
+//! debounced_input_config!(
+//!     SomeDebouncedInputConfig,
+//!     debounce_timer: Timer<ManualTimestamp> = Timer::new(3)
+//! );
+//!
+//! ManualClock::reset();
+//!
+//! let input_switch = ScriptedInputSwitch::new([(0, false), (5, true), (6, false), (7, true)]);
+//! let mut debounced_input = DebouncedInput::<_, SomeDebouncedInputConfig>::new(input_switch);
+//!
+//! let events = run_for(&mut debounced_input, 15, 1).unwrap();
+//!
+//! assert_event_sequence(&events, &[DebouncedInputEvent::Low, DebouncedInputEvent::High], &[DebouncedInputEvent::Rise]);
+//! ```
+
+use crate::Control;
+
+use core::{cell::Cell, convert::Infallible, fmt::Debug};
+use std::vec::Vec;
+use switch_hal::InputSwitch;
+use timestamp_source::Timestamp;
+
+std::thread_local! {
+    static CLOCK_TICKS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Manual clock that drives [`ManualTimestamp`](crate::testing::ManualTimestamp).
+///
+/// The clock is thread-local, so tests that run in parallel don't affect each other.
+pub struct ManualClock;
+
+impl ManualClock {
+    /// Returns the current ticks of the clock.
+    pub fn ticks() -> u64 {
+        CLOCK_TICKS.with(Cell::get)
+    }
+
+    /// Sets the current ticks of the clock.
+    pub fn set(ticks: u64) {
+        CLOCK_TICKS.with(|clock_ticks| clock_ticks.set(ticks));
+    }
+
+    /// Advances the clock by `ticks`.
+    pub fn advance(ticks: u64) {
+        CLOCK_TICKS.with(|clock_ticks| clock_ticks.set(clock_ticks.get() + ticks));
+    }
+
+    /// Resets the clock to zero.
+    pub fn reset() {
+        Self::set(0);
+    }
+}
+
+/// Timestamp that is taken from [`ManualClock`](crate::testing::ManualClock).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ManualTimestamp {
+    ticks: u64,
+}
+
+impl ManualTimestamp {
+    /// Returns the ticks of the timestamp.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+}
+
+impl Timestamp for ManualTimestamp {
+    type Duration = u64;
+    type Error = ();
+
+    fn now() -> Self {
+        ManualTimestamp {
+            ticks: ManualClock::ticks(),
+        }
+    }
+
+    fn duration_since_epoch(self) -> Self::Duration {
+        self.ticks
+    }
+
+    fn duration_since(&self, other: &Self) -> Result<Self::Duration, Self::Error> {
+        self.ticks.checked_sub(other.ticks).ok_or(())
+    }
+}
+
+/// [`InputSwitch`](switch_hal::InputSwitch) that follows a time-keyed waveform of [`ManualClock`](crate::testing::ManualClock).
+///
+/// The waveform consists of `(ticks, result)` points, the switch returns the result of the last point
+/// that isn't later than the current ticks, `Ok(false)` is returned before the first point.
+pub struct ScriptedInputSwitch<E: Copy = Infallible> {
+    waveform: Vec<(u64, Result<bool, E>)>,
+}
+
+impl ScriptedInputSwitch {
+    /// Creates a new infallible [`ScriptedInputSwitch`] from `(ticks, level)` points.
+    pub fn new(levels: impl IntoIterator<Item = (u64, bool)>) -> Self {
+        Self::with_results(levels.into_iter().map(|(ticks, level)| (ticks, Ok(level))))
+    }
+}
+
+impl<E: Copy> ScriptedInputSwitch<E> {
+    /// Creates a new [`ScriptedInputSwitch<E>`] from `(ticks, result)` points, e.g. to inject read errors.
+    pub fn with_results(results: impl IntoIterator<Item = (u64, Result<bool, E>)>) -> Self {
+        let mut waveform: Vec<_> = results.into_iter().collect();
+        waveform.sort_by_key(|(ticks, _)| *ticks);
+
+        ScriptedInputSwitch { waveform }
+    }
+
+    /// Borrow the waveform.
+    pub fn borrow_waveform(&self) -> &[(u64, Result<bool, E>)] {
+        &self.waveform
+    }
+}
+
+impl<E: Copy> InputSwitch for ScriptedInputSwitch<E> {
+    type Error = E;
+
+    fn is_active(&self) -> Result<bool, Self::Error> {
+        let ticks = ManualClock::ticks();
+        let index = self.waveform.partition_point(|(point, _)| *point <= ticks);
+
+        match index {
+            0 => Ok(false),
+            _ => self.waveform[index - 1].1,
+        }
+    }
+}
+
+/// Updates `control` `updates` times and advances [`ManualClock`](crate::testing::ManualClock)
+/// by `step` ticks after each update, returns the collected events.
+/// # Errors
+/// This function will return the first error of [`Control::update`].
+pub fn run_for<C: Control>(
+    control: &mut C,
+    updates: usize,
+    step: u64,
+) -> Result<Vec<C::Event>, C::Error> {
+    let mut events = Vec::with_capacity(updates);
+
+    for _ in 0..updates {
+        events.push(control.update()?);
+        ManualClock::advance(step);
+    }
+
+    Ok(events)
+}
+
+/// Returns `events` without `idle` events, e.g. without [`DebouncedInputEvent::Low`](crate::DebouncedInputEvent::Low).
+pub fn significant_events<E: Copy + PartialEq>(events: &[E], idle: &[E]) -> Vec<E> {
+    events
+        .iter()
+        .filter(|event| !idle.contains(event))
+        .copied()
+        .collect()
+}
+
+/// Asserts that `events` without `idle` events are equal to `expected`.
+/// # Panics
+/// This function panics if the sequences are not equal.
+#[track_caller]
+pub fn assert_event_sequence<E: Copy + PartialEq + Debug>(
+    events: &[E],
+    idle: &[E],
+    expected: &[E],
+) {
+    let actual = significant_events(events, idle);
+
+    assert!(
+        actual == expected,
+        "event sequence mismatch\n  actual: {:?}\nexpected: {:?}\n     all: {:?}",
+        actual,
+        expected,
+        events
+    );
+}
@@ -0,0 +1,215 @@
+use crate::{Control, DebouncedInputEvent, TouchKeyError};
+
+use core::marker::PhantomData;
+use num_traits::ToPrimitive;
+use switch_hal::{InputSwitch, OutputSwitch};
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`TouchKey`](crate::TouchKey).
+pub trait TouchKeyConfig {
+    /// Elapsed timer type that used for [`TouchKey`](crate::TouchKey).
+    type Timer: ElapsedTimer;
+
+    /// Elapsed timer instance that used for [`TouchKey`](crate::TouchKey).
+    /// The measurement of charge time is aborted if this timer is elapsed.
+    const MEASURE_TIMEOUT_TIMER: Self::Timer;
+
+    /// The charge time above the baseline to detect a touch, in durations of the timestamp.
+    const TOUCH_THRESHOLD: u16;
+
+    /// The charge time above the baseline to detect a release, must be less than `TOUCH_THRESHOLD`.
+    const RELEASE_THRESHOLD: u16;
+
+    /// The speed of baseline drift tracking, the baseline moves by `1 / 2^BASELINE_SHIFT` of a difference.
+    const BASELINE_SHIFT: u8;
+}
+
+/// Concrete implementation of capacitive touch key by RC charge time measurement.
+///
+/// Every update discharges the pad by `OutputSwitch::off`, charges it by `OutputSwitch::on`
+/// through a resistor and measures the time until `InputSwitch` becomes active by the timestamp of
+/// [`MEASURE_TIMEOUT_TIMER`](crate::TouchKeyConfig::MEASURE_TIMEOUT_TIMER), so its duration must be convertible to a number.
+/// The baseline of charge time is tracked while the key isn't touched to compensate a drift.
+///
+/// # Type Params
+/// `Output` - [`OutputSwitch`](switch_hal::OutputSwitch) that charges the pad through a resistor.
+///
+/// `Input` - [`InputSwitch`](switch_hal::InputSwitch) that senses the pad voltage.
+///
+/// `Config` - [`TouchKeyConfig`](crate::TouchKeyConfig) that provides configs for touch key.
+///
+/// # Example
+/// ```ignore
+/// touch_key_config!(
+///     SomeTouchKeyConfig,
+///     measure_timeout_timer: MyElapsedTimer = MyElapsedTimer::new(1.millis()),
+///     touch_threshold: 40,
+///     release_threshold: 25,
+///     baseline_shift: 4
+/// );
+///
+/// type MyTouchKey<Output, Input> = TouchKey<Output, Input, SomeTouchKeyConfig>;
+///
+/// let mut touch_key = MyTouchKey::new(
+///     drive_pin.into_active_high_switch(),
+///     sense_pin.into_active_high_switch(),
+/// );
+///
+/// loop {
+///     match touch_key.update().unwrap() {
+///         DebouncedInputEvent::Rise => do_something_upon_touch(),
+///         DebouncedInputEvent::Fall => do_something_upon_release(),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct TouchKey<Output: OutputSwitch, Input: InputSwitch, Config: TouchKeyConfig> {
+    output_switch: Output,
+    input_switch: Input,
+    baseline: Option<u32>,
+    charge_time: u16,
+    touched: bool,
+    config: PhantomData<Config>,
+}
+
+const BASELINE_FRACTION_BITS: u32 = 4;
+
+impl<Output: OutputSwitch, Input: InputSwitch, Config: TouchKeyConfig>
+    TouchKey<Output, Input, Config>
+where
+    <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Duration: ToPrimitive,
+{
+    /// Creates a new [`TouchKey<Output, Input, Config>`] from concretes `Output`, `Input`.
+    /// The baseline is initialized by the first measurement, so the key must not be touched upon the first update.
+    ///
+    /// `output_switch` - an concrete instance of `Output`.
+    ///
+    /// `input_switch` - an concrete instance of `Input`.
+    pub fn new(output_switch: Output, input_switch: Input) -> Self {
+        TouchKey {
+            output_switch,
+            input_switch,
+            baseline: None,
+            charge_time: 0,
+            touched: false,
+            config: PhantomData::<Config>,
+        }
+    }
+
+    /// Returns the is touched state.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    /// Returns the last measured charge time in durations of the timestamp, saturated to `u16::MAX`.
+    pub fn charge_time(&self) -> u16 {
+        self.charge_time
+    }
+
+    /// Returns the baseline of charge time, `None` before the first update.
+    pub fn baseline(&self) -> Option<u16> {
+        self.baseline
+            .map(|baseline| (baseline >> BASELINE_FRACTION_BITS) as u16)
+    }
+
+    /// Consumes `self` and release `(Output, Input)`.
+    pub fn release_switches(self) -> (Output, Input) {
+        (self.output_switch, self.input_switch)
+    }
+
+    fn measure(&mut self) -> Result<u16, <Self as Control>::Error> {
+        self.output_switch
+            .off()
+            .map_err(TouchKeyError::OutputSwitch)?;
+
+        let charge_time = self
+            .output_switch
+            .on()
+            .map_err(TouchKeyError::OutputSwitch)
+            .and_then(|_| self.charge());
+
+        // the pad is discharged even if the measurement is failed
+        let discharge = self
+            .output_switch
+            .off()
+            .map_err(TouchKeyError::OutputSwitch);
+
+        let charge_time = charge_time?;
+        discharge?;
+
+        Ok(charge_time)
+    }
+
+    fn charge(&self) -> Result<u16, <Self as Control>::Error> {
+        let start = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        let end = loop {
+            let active = self
+                .input_switch
+                .is_active()
+                .map_err(TouchKeyError::InputSwitch)?;
+
+            let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+            if active
+                || Config::MEASURE_TIMEOUT_TIMER
+                    .timeout(&start, &now)
+                    .map_err(TouchKeyError::ElapsedTimer)?
+            {
+                break now;
+            }
+        };
+
+        Ok(end
+            .duration_since(&start)
+            .map_err(TouchKeyError::ElapsedTimer)?
+            .to_u16()
+            .unwrap_or(u16::MAX))
+    }
+}
+
+impl<Output: OutputSwitch, Input: InputSwitch, Config: TouchKeyConfig> Control
+    for TouchKey<Output, Input, Config>
+where
+    <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Duration: ToPrimitive,
+{
+    type Event = DebouncedInputEvent;
+    type Error = TouchKeyError<
+        <<Config::Timer as ElapsedTimer>::Timestamp as Timestamp>::Error,
+        Output::Error,
+        Input::Error,
+    >;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let charge_time = self.measure()?;
+        self.charge_time = charge_time;
+
+        let sample = (charge_time as u32) << BASELINE_FRACTION_BITS;
+        let baseline = *self.baseline.get_or_insert(sample);
+        let delta = (charge_time as u32).saturating_sub(baseline >> BASELINE_FRACTION_BITS);
+
+        let event = if self.touched {
+            if delta < Config::RELEASE_THRESHOLD as u32 {
+                self.touched = false;
+                DebouncedInputEvent::Fall
+            } else {
+                DebouncedInputEvent::High
+            }
+        } else if delta > Config::TOUCH_THRESHOLD as u32 {
+            self.touched = true;
+            DebouncedInputEvent::Rise
+        } else {
+            DebouncedInputEvent::Low
+        };
+
+        if !self.touched {
+            let shift = (Config::BASELINE_SHIFT as u32).min(u32::BITS - 1);
+            self.baseline = Some(if sample >= baseline {
+                baseline + ((sample - baseline) >> shift)
+            } else {
+                baseline - ((baseline - sample) >> shift)
+            });
+        }
+
+        Ok(event)
+    }
+}
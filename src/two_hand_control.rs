@@ -0,0 +1,175 @@
+use crate::{Control, DebouncedInput, DebouncedInputConfig, Error};
+
+use switch_hal::InputSwitch;
+use timestamp_source::{ElapsedTimer, Timestamp};
+
+/// Represents a config for [`TwoHandControl`](crate::TwoHandControl).
+pub trait TwoHandControlConfig: DebouncedInputConfig {
+    /// Elapsed timer instance that used for [`TwoHandControl`](crate::TwoHandControl).
+    /// Both buttons must be pressed before this timer is elapsed since the first button press.
+    const SIMULTANEITY_TIMER: Self::Timer;
+}
+
+/// The state machine of [`TwoHandControl`](crate::TwoHandControl).
+pub enum TwoHandControlState<T> {
+    Armed,
+    Pressing(T),
+    Enabled,
+    Releasing,
+    Fault,
+}
+
+/// Concrete implementation of two-hand control (press machines and etc.) over two debounced inputs.
+///
+/// The control is enabled if both buttons are pressed within
+/// [`SIMULTANEITY_TIMER`](crate::TwoHandControlConfig::SIMULTANEITY_TIMER) since the first press,
+/// a release of any button disables it. If the window is elapsed with only one button pressed,
+/// the control is faulted. In any case both buttons must be released to re-arm the control,
+/// it's also required upon creation and after a read error. Both buttons are updated even if
+/// one of them returns an error, the first error is returned.
+///
+/// # Type Params
+/// `Switch` - [`InputSwitch`](switch_hal::InputSwitch) that provides input of one button.
+///
+/// `Config` - [`TwoHandControlConfig`](crate::TwoHandControlConfig) that provides configs for two-hand control.
+///
+/// # Example
+/// ```ignore
+/// two_hand_control_config!(
+///     SomeTwoHandControlConfig,
+///     debounce_timer: MyElapsedTimer = MyElapsedTimer::new(20.millis()),
+///     simultaneity_timer: MyElapsedTimer = MyElapsedTimer::new(500.millis())
+/// );
+///
+/// type MyTwoHandControl<Switch> = TwoHandControl<Switch, SomeTwoHandControlConfig>;
+///
+/// let mut two_hand_control = MyTwoHandControl::new(
+///     pin_left.into_active_low_switch(),
+///     pin_right.into_active_low_switch(),
+/// );
+///
+/// loop {
+///     match two_hand_control.update().unwrap() {
+///         TwoHandControlEvent::Enabled => press.run(),
+///         TwoHandControlEvent::Disabled => press.stop(),
+///         TwoHandControlEvent::Fault => {
+///             press.stop();
+///             show_fault();
+///         }
+///     }
+/// }
+/// ```
+pub struct TwoHandControl<Switch: InputSwitch, Config: TwoHandControlConfig> {
+    debounced_inputs: [DebouncedInput<Switch, Config>; 2],
+    state: TwoHandControlState<<Config::Timer as ElapsedTimer>::Timestamp>,
+}
+
+/// The event result of update [`TwoHandControl`](crate::TwoHandControl).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoHandControlEvent {
+    /// Both buttons are pressed simultaneously and held, the output may be enabled.
+    Enabled,
+    /// The output must be disabled.
+    Disabled,
+    /// The simultaneity window is elapsed with only one button pressed, the output must be disabled.
+    Fault,
+}
+
+impl<Switch: InputSwitch, Config: TwoHandControlConfig> TwoHandControl<Switch, Config> {
+    /// Creates a new [`TwoHandControl<Switch, Config>`] from concretes `Switch`es.
+    ///
+    /// `left_input_switch` - an concrete instance of `Switch` for the left button.
+    ///
+    /// `right_input_switch` - an concrete instance of `Switch` for the right button.
+    pub fn new(left_input_switch: Switch, right_input_switch: Switch) -> Self {
+        TwoHandControl {
+            debounced_inputs: [
+                DebouncedInput::new(left_input_switch),
+                DebouncedInput::new(right_input_switch),
+            ],
+            state: TwoHandControlState::Releasing,
+        }
+    }
+
+    /// Returns true if the control is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self.state, TwoHandControlState::Enabled)
+    }
+
+    /// Returns true if the control is faulted.
+    pub fn is_fault(&self) -> bool {
+        matches!(self.state, TwoHandControlState::Fault)
+    }
+
+    /// Borrow debounced inputs of the left and the right buttons.
+    pub fn borrow_debounced_inputs(&self) -> &[DebouncedInput<Switch, Config>; 2] {
+        &self.debounced_inputs
+    }
+
+    /// Consumes `self` and release `Switch`es of the left and the right buttons.
+    pub fn release_input_switches(self) -> [Switch; 2] {
+        self.debounced_inputs
+            .map(DebouncedInput::release_input_switch)
+    }
+}
+
+impl<Switch: InputSwitch, Config: TwoHandControlConfig> Control for TwoHandControl<Switch, Config> {
+    type Event = TwoHandControlEvent;
+    type Error = <DebouncedInput<Switch, Config> as Control>::Error;
+
+    fn update(&mut self) -> Result<Self::Event, Self::Error> {
+        let mut result = Ok(());
+        for debounced_input in self.debounced_inputs.iter_mut() {
+            if let Err(error) = debounced_input.update() {
+                result = result.and(Err(error));
+            }
+        }
+
+        if let Err(error) = result {
+            if !self.is_fault() {
+                self.state = TwoHandControlState::Releasing;
+            }
+            return Err(error);
+        }
+
+        let pressed = self
+            .debounced_inputs
+            .iter()
+            .filter(|debounced_input| debounced_input.is_high())
+            .count();
+        let now = <Config::Timer as ElapsedTimer>::Timestamp::now();
+
+        self.state = match &self.state {
+            TwoHandControlState::Armed if pressed == 2 => TwoHandControlState::Enabled,
+            TwoHandControlState::Armed if pressed == 1 => TwoHandControlState::Pressing(now),
+            TwoHandControlState::Pressing(_) if pressed == 0 => TwoHandControlState::Armed,
+            TwoHandControlState::Pressing(start) => {
+                if Config::SIMULTANEITY_TIMER
+                    .timeout(start, &now)
+                    .map_err(Error::ElapsedTimer)?
+                {
+                    TwoHandControlState::Fault
+                } else if pressed == 2 {
+                    TwoHandControlState::Enabled
+                } else {
+                    return Ok(TwoHandControlEvent::Disabled);
+                }
+            }
+            TwoHandControlState::Enabled if pressed == 2 => TwoHandControlState::Enabled,
+            TwoHandControlState::Enabled | TwoHandControlState::Releasing if pressed == 0 => {
+                TwoHandControlState::Armed
+            }
+            TwoHandControlState::Enabled => TwoHandControlState::Releasing,
+            TwoHandControlState::Fault if pressed == 0 => TwoHandControlState::Armed,
+            TwoHandControlState::Armed => TwoHandControlState::Armed,
+            TwoHandControlState::Releasing => TwoHandControlState::Releasing,
+            TwoHandControlState::Fault => TwoHandControlState::Fault,
+        };
+
+        Ok(match self.state {
+            TwoHandControlState::Enabled => TwoHandControlEvent::Enabled,
+            TwoHandControlState::Fault => TwoHandControlEvent::Fault,
+            _ => TwoHandControlEvent::Disabled,
+        })
+    }
+}
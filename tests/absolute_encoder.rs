@@ -0,0 +1,115 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    absolute_encoder_config, AbsoluteEncoder, AbsoluteEncoderCoding, AbsoluteEncoderEvent, Control,
+    Error,
+};
+use timestamp_source::Timer;
+
+absolute_encoder_config!(
+    TestAbsoluteEncoderConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(2),
+    coding: AbsoluteEncoderCoding::Gray
+);
+
+type TestAbsoluteEncoder<Switch> = AbsoluteEncoder<Switch, 3, TestAbsoluteEncoderConfig>;
+
+fn bits_of(codes: &[u32], bit: usize) -> Vec<Result<bool, &'static str>> {
+    codes
+        .iter()
+        .map(|code| Ok(code & (1 << bit) != 0))
+        .collect()
+}
+
+#[test]
+fn absolute_encoder_coding() {
+    assert_eq!(AbsoluteEncoderCoding::Binary.decode(0b101), Some(5));
+
+    let gray_codes = [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100];
+    for (position, code) in gray_codes.into_iter().enumerate() {
+        assert_eq!(
+            AbsoluteEncoderCoding::Gray.decode(code),
+            Some(position as u32)
+        );
+    }
+
+    assert_eq!(AbsoluteEncoderCoding::Bcd.decode(0x0), Some(0));
+    assert_eq!(AbsoluteEncoderCoding::Bcd.decode(0x9), Some(9));
+    assert_eq!(AbsoluteEncoderCoding::Bcd.decode(0x42), Some(42));
+    assert_eq!(AbsoluteEncoderCoding::Bcd.decode(0xA), None);
+}
+
+#[test]
+fn absolute_encoder_success() {
+    let codes = [0, 0, 1, 3, 1, 1, 1, 1, 3, 3, 3, 3];
+
+    let bit_0 = bits_of(&codes, 0);
+    let bit_1 = bits_of(&codes, 1);
+    let bit_2 = bits_of(&codes, 2);
+
+    let mut absolute_encoder = TestAbsoluteEncoder::new([
+        MockInputSwitch::new(&bit_0),
+        MockInputSwitch::new(&bit_1),
+        MockInputSwitch::new(&bit_2),
+    ]);
+
+    assert_eq!(absolute_encoder.position(), Some(0));
+
+    for _ in 0..5 {
+        assert_eq!(
+            absolute_encoder.update(),
+            Ok(AbsoluteEncoderEvent::NoChange)
+        );
+        assert_eq!(absolute_encoder.position(), Some(0));
+    }
+
+    assert_eq!(
+        absolute_encoder.update(),
+        Ok(AbsoluteEncoderEvent::PositionChanged(1))
+    );
+    assert_eq!(absolute_encoder.position(), Some(1));
+
+    for _ in 0..3 {
+        assert_eq!(
+            absolute_encoder.update(),
+            Ok(AbsoluteEncoderEvent::NoChange)
+        );
+        assert_eq!(absolute_encoder.position(), Some(1));
+    }
+
+    assert_eq!(
+        absolute_encoder.update(),
+        Ok(AbsoluteEncoderEvent::PositionChanged(2))
+    );
+    assert_eq!(absolute_encoder.position(), Some(2));
+    assert_eq!(absolute_encoder.code(), 3);
+
+    assert_eq!(
+        absolute_encoder.update(),
+        Ok(AbsoluteEncoderEvent::NoChange)
+    );
+}
+
+#[test]
+fn absolute_encoder_error() {
+    let bit_0 = [Ok(false), Ok(false), Ok(false)];
+    let bit_1 = [Ok(false), Err("Some error"), Ok(false)];
+    let bit_2 = [Ok(false), Ok(false), Ok(false)];
+
+    let mut absolute_encoder = TestAbsoluteEncoder::new([
+        MockInputSwitch::new(&bit_0),
+        MockInputSwitch::new(&bit_1),
+        MockInputSwitch::new(&bit_2),
+    ]);
+
+    assert_eq!(
+        absolute_encoder.update(),
+        Err(Error::InputSwitch("Some error"))
+    );
+    assert_eq!(
+        absolute_encoder.update(),
+        Ok(AbsoluteEncoderEvent::NoChange)
+    );
+}
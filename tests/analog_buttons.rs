@@ -0,0 +1,83 @@
+mod common;
+
+use crate::common::{MockAnalogInput, MockTimestamp};
+
+use embedded_controls::{
+    analog_buttons_config, AnalogButtons, AnalogButtonsError, AnalogButtonsEvent, Control,
+};
+use timestamp_source::Timer;
+
+analog_buttons_config!(
+    TestAnalogButtonsConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(2),
+    thresholds: &[(0, 100), (400, 600)]
+);
+
+type TestAnalogButtons<Input> = AnalogButtons<Input, TestAnalogButtonsConfig>;
+
+#[test]
+fn analog_buttons_success() {
+    let value_results = [
+        Ok(1000),
+        Ok(50),
+        Ok(1000),
+        Ok(50),
+        Ok(50),
+        Ok(50),
+        Ok(50),
+        Ok(500),
+        Ok(500),
+        Ok(500),
+        Ok(500),
+        Ok(1000),
+        Ok(1000),
+        Ok(1000),
+        Ok(1000),
+    ];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut analog_buttons = TestAnalogButtons::new(analog_input);
+
+    for _ in 0..4 {
+        assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::NoKey));
+        assert_eq!(analog_buttons.key(), None);
+    }
+
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Pressed(0)));
+    assert_eq!(analog_buttons.key(), Some(0));
+
+    for _ in 0..3 {
+        assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Held(0)));
+    }
+
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Released(0)));
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Pressed(1)));
+
+    for _ in 0..2 {
+        assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Held(1)));
+    }
+
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Released(1)));
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::NoKey));
+}
+
+#[test]
+fn analog_buttons_error_keeps_key() {
+    let value_results = [Ok(50), Ok(1000), Err("Some error"), Ok(1000), Ok(1000)];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut analog_buttons = TestAnalogButtons::new(analog_input);
+
+    assert_eq!(analog_buttons.key(), Some(0));
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Held(0)));
+
+    // the held key and the started release debounce are kept
+    assert_eq!(
+        analog_buttons.update(),
+        Err(AnalogButtonsError::AnalogInput("Some error"))
+    );
+    assert_eq!(analog_buttons.key(), Some(0));
+
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::Released(0)));
+    assert_eq!(analog_buttons.update(), Ok(AnalogButtonsEvent::NoKey));
+}
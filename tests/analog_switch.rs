@@ -0,0 +1,75 @@
+mod common;
+
+use crate::common::{MockAnalogInput, MockTimestamp};
+
+use embedded_controls::{
+    debounced_input_config, AnalogSwitch, Control, DebouncedInput, DebouncedInputEvent,
+};
+use switch_hal::InputSwitch;
+use timestamp_source::Timer;
+
+debounced_input_config!(
+    TestDebouncedInputConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1)
+);
+
+type TestDebouncedInput<Switch> = DebouncedInput<Switch, TestDebouncedInputConfig>;
+
+#[test]
+fn analog_switch_thresholds() {
+    let value_results = [
+        Ok(100),
+        Ok(500),
+        Ok(600),
+        Ok(550),
+        Ok(400),
+        Ok(300),
+        Ok(450),
+    ];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let analog_switch = AnalogSwitch::new(analog_input, 400, 600);
+
+    let expected = [false, false, true, true, false, false, false];
+    for active in expected {
+        assert_eq!(analog_switch.is_active(), Ok(active));
+    }
+}
+
+#[test]
+fn analog_switch_debounced_input() {
+    let value_results = [
+        Ok(100),
+        Ok(700),
+        Ok(500),
+        Ok(500),
+        Ok(300),
+        Ok(300),
+        Ok(300),
+    ];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut debounced_input = TestDebouncedInput::new(AnalogSwitch::new(analog_input, 400, 600));
+
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Rise));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::High));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::High));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Fall));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+}
+
+#[test]
+fn analog_switch_error_keeps_state() {
+    let value_results = [Ok(700), Err("Some error"), Ok(500), Ok(300)];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let analog_switch = AnalogSwitch::new(analog_input, 400, 600);
+
+    assert_eq!(analog_switch.is_active(), Ok(true));
+    assert_eq!(analog_switch.is_active(), Err("Some error"));
+
+    // the active state is kept inside the hysteresis band after the error
+    assert_eq!(analog_switch.is_active(), Ok(true));
+    assert_eq!(analog_switch.is_active(), Ok(false));
+}
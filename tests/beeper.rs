@@ -0,0 +1,127 @@
+mod common;
+
+use crate::common::{MockOutputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    BeepPattern, BeepStep, Beeper, BeeperEvent, Control, EncoderEvent, OutputError, SwitchTone,
+    ToneOutput,
+};
+use timestamp_source::Timer;
+
+type TestTimer = Timer<MockTimestamp>;
+
+const CLICK: BeepPattern<TestTimer> = &[BeepStep::new(Some(4000), Timer::new(1))];
+
+const CONFIRM: BeepPattern<TestTimer> = &[
+    BeepStep::new(Some(2000), Timer::new(2)),
+    BeepStep::new(None, Timer::new(1)),
+];
+
+const ENCODER_FEEDBACK: &[(EncoderEvent, BeepPattern<TestTimer>)] = &[
+    (EncoderEvent::ClockwiseTurn, CLICK),
+    (EncoderEvent::CounterClockwiseTurn, CLICK),
+];
+
+#[derive(Default)]
+struct MockTone {
+    tones: Vec<Option<u32>>,
+}
+
+impl ToneOutput for MockTone {
+    type Error = &'static str;
+
+    fn set_tone(&mut self, frequency: Option<u32>) -> Result<(), Self::Error> {
+        self.tones.push(frequency);
+        Ok(())
+    }
+}
+
+#[test]
+fn beeper_queue() {
+    let mut beeper = Beeper::<_, TestTimer, 2>::new(MockTone::default());
+
+    assert!(beeper.play(CLICK));
+    assert!(beeper.play(CONFIRM));
+    assert!(!beeper.play(CLICK));
+    assert_eq!(beeper.queued(), 2);
+
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Playing));
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Finished));
+    for _ in 0..3 {
+        assert_eq!(beeper.update(), Ok(BeeperEvent::Playing));
+    }
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Finished));
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Idle));
+    assert!(!beeper.is_playing());
+
+    assert_eq!(
+        beeper.release_tone_output().tones,
+        [Some(4000), None, Some(2000), None]
+    );
+}
+
+#[test]
+fn beeper_repeated_pattern() {
+    let mut beeper = Beeper::<_, TestTimer, 2>::new(MockTone::default());
+
+    assert!(beeper.play(CLICK));
+    assert!(beeper.play(CLICK));
+
+    // each click is heard separately
+    for _ in 0..2 {
+        assert_eq!(beeper.update(), Ok(BeeperEvent::Playing));
+        assert_eq!(beeper.update(), Ok(BeeperEvent::Finished));
+    }
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Idle));
+
+    assert_eq!(
+        beeper.release_tone_output().tones,
+        [Some(4000), None, Some(4000), None]
+    );
+}
+
+#[test]
+fn beeper_feedback() {
+    let mut beeper = Beeper::<_, TestTimer, 4>::new(SwitchTone::new(MockOutputSwitch::new()));
+
+    assert!(beeper.feedback(&EncoderEvent::NoTurn, ENCODER_FEEDBACK));
+    assert_eq!(beeper.queued(), 0);
+
+    assert!(beeper.feedback(&EncoderEvent::ClockwiseTurn, ENCODER_FEEDBACK));
+    assert_eq!(beeper.queued(), 1);
+
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Playing));
+    assert!(beeper.borrow_tone_output().borrow_output_switch().is_on());
+
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Finished));
+    assert!(!beeper.borrow_tone_output().borrow_output_switch().is_on());
+
+    beeper.play(CONFIRM);
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Playing));
+    beeper.stop();
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Idle));
+    assert!(!beeper.borrow_tone_output().borrow_output_switch().is_on());
+}
+
+#[test]
+fn beeper_error() {
+    let results = [Ok(()), Err("Some error"), Ok(())];
+    let mut beeper =
+        Beeper::<_, TestTimer, 1>::new(SwitchTone::new(MockOutputSwitch::with_results(&results)));
+
+    beeper.play(CLICK);
+
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Playing));
+    assert!(beeper.borrow_tone_output().borrow_output_switch().is_on());
+
+    assert_eq!(
+        beeper.update(),
+        Err(OutputError::OutputSwitch("Some error"))
+    );
+    assert!(!beeper.is_playing());
+    assert!(beeper.borrow_tone_output().borrow_output_switch().is_on());
+
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Finished));
+    assert!(!beeper.borrow_tone_output().borrow_output_switch().is_on());
+    assert_eq!(beeper.update(), Ok(BeeperEvent::Idle));
+}
@@ -0,0 +1,276 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{chord_config, Chord, ChordEvent, Control, Error};
+use timestamp_source::Timer;
+
+chord_config!(
+    TestChordConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1),
+    simultaneity_timer: Timer<MockTimestamp> = Timer::new(6)
+);
+
+type TestChord<Switch> = Chord<Switch, 2, TestChordConfig>;
+
+#[test]
+fn chord_success() {
+    let state_results_a = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let state_results_b = [
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    let mut chord = TestChord::new([input_switch_a, input_switch_b]);
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::Chord(0b11)));
+    assert_eq!(chord.pressed_mask(), 0b11);
+
+    for _ in 0..2 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::ChordReleased(0b11)));
+    assert_eq!(chord.pressed_mask(), 0);
+
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+}
+
+#[test]
+fn chord_single_keys() {
+    let state_results_a = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        // single long press of key B
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let state_results_b = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        // single long press of key B
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    let mut chord = TestChord::new([input_switch_a, input_switch_b]);
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyPressed(0)));
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyReleased(0)));
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyPressed(1)));
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyReleased(1)));
+    assert_eq!(chord.pressed_mask(), 0);
+
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+}
+
+#[test]
+fn chord_extend_held_key() {
+    let state_results_a = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let state_results_b = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    let mut chord = TestChord::new([input_switch_a, input_switch_b]);
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyPressed(0)));
+
+    for _ in 0..4 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::Chord(0b11)));
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    assert_eq!(chord.update(), Ok(ChordEvent::ChordReleased(0b11)));
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+}
+
+#[test]
+fn chord_error_keeps_state() {
+    let state_results_a = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Err("Some error"),
+        Ok(true),
+        Ok(true),
+    ];
+
+    let state_results_b = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+    ];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    let mut chord = TestChord::new([input_switch_a, input_switch_b]);
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyPressed(0)));
+
+    // key A fails, key B is still updated and starts to rise
+    assert_eq!(chord.update(), Err(Error::InputSwitch("Some error")));
+    assert_eq!(chord.pressed_mask(), 0b01);
+
+    // key A recovers and key B extends held key A
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    assert_eq!(chord.pressed_mask(), 0b11);
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+}
+
+#[test]
+fn chord_error_keeps_press() {
+    let state_results_a = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+    ];
+    let state_results_b = [
+        Ok(false),
+        Ok(false),
+        Err("Some error"),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    let mut chord = TestChord::new([input_switch_a, input_switch_b]);
+
+    assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+
+    // key A finishes debouncing while key B fails, the press is handled after recovery
+    assert_eq!(chord.update(), Err(Error::InputSwitch("Some error")));
+    assert_eq!(chord.pressed_mask(), 0b01);
+
+    for _ in 0..2 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyPressed(0)));
+
+    for _ in 0..3 {
+        assert_eq!(chord.update(), Ok(ChordEvent::NoEvent));
+    }
+
+    assert_eq!(chord.update(), Ok(ChordEvent::KeyReleased(0)));
+}
@@ -1,6 +1,12 @@
-use std::{cell::RefCell, ops::AddAssign};
+#![allow(dead_code)]
 
-use switch_hal::InputSwitch;
+use std::{
+    cell::{Cell, RefCell},
+    ops::AddAssign,
+};
+
+use embedded_controls::AnalogInput;
+use switch_hal::{InputSwitch, OutputSwitch};
 use timestamp_source::Timestamp;
 
 pub struct MockTimestamp {
@@ -17,12 +23,14 @@ impl Timestamp for MockTimestamp {
     type Error = ();
 
     fn now() -> Self {
-        static mut TICKS: u32 = 0;
-
-        unsafe {
-            TICKS += 1;
-            MockTimestamp { ticks: TICKS }
+        thread_local! {
+            static TICKS: Cell<u32> = const { Cell::new(0) };
         }
+
+        TICKS.with(|ticks| {
+            ticks.set(ticks.get() + 1);
+            MockTimestamp { ticks: ticks.get() }
+        })
     }
 
     fn duration_since_epoch(self) -> Self::Duration {
@@ -43,7 +51,7 @@ impl<'a> MockInputSwitch<'a> {
     }
 
     pub fn next(&self) -> Result<bool, &'static str> {
-        let state_result = self.state_results[*self.index.borrow() as usize];
+        let state_result = self.state_results[*self.index.borrow()];
 
         self.index.try_borrow_mut().unwrap().add_assign(1);
 
@@ -58,3 +66,94 @@ impl<'a> InputSwitch for MockInputSwitch<'a> {
         self.next()
     }
 }
+
+pub struct MockAnalogInput<'a> {
+    value_results: &'a [Result<u16, &'static str>],
+    index: RefCell<usize>,
+}
+
+impl<'a> MockAnalogInput<'a> {
+    pub fn new(value_results: &'a [Result<u16, &'static str>]) -> Self {
+        MockAnalogInput {
+            value_results,
+            index: RefCell::new(Default::default()),
+        }
+    }
+
+    pub fn next(&self) -> Result<u16, &'static str> {
+        let value_result = self.value_results[*self.index.borrow()];
+
+        self.index.try_borrow_mut().unwrap().add_assign(1);
+
+        value_result
+    }
+}
+
+impl<'a> AnalogInput for MockAnalogInput<'a> {
+    type Error = &'static str;
+
+    fn read(&self) -> Result<u16, Self::Error> {
+        self.next()
+    }
+}
+
+pub struct MockOutputSwitch<'a> {
+    results: Option<&'a [Result<(), &'static str>]>,
+    index: usize,
+    is_on: bool,
+}
+
+impl<'a> MockOutputSwitch<'a> {
+    pub fn new() -> Self {
+        MockOutputSwitch {
+            results: None,
+            index: Default::default(),
+            is_on: false,
+        }
+    }
+
+    pub fn with_results(results: &'a [Result<(), &'static str>]) -> Self {
+        MockOutputSwitch {
+            results: Some(results),
+            index: Default::default(),
+            is_on: false,
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    fn next_result(&mut self) -> Result<(), &'static str> {
+        let result = match self.results {
+            Some(results) => results[self.index],
+            None => Ok(()),
+        };
+
+        self.index.add_assign(1);
+
+        result
+    }
+}
+
+impl<'a> Default for MockOutputSwitch<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> OutputSwitch for MockOutputSwitch<'a> {
+    type Error = &'static str;
+
+    fn on(&mut self) -> Result<(), Self::Error> {
+        self.next_result()?;
+        self.is_on = true;
+        Ok(())
+    }
+
+    fn off(&mut self) -> Result<(), Self::Error> {
+        self.next_result()?;
+        self.is_on = false;
+        Ok(())
+    }
+}
@@ -120,3 +120,88 @@ fn debounced_input_error() {
     assert!(!debounced_input.is_high());
     assert!(debounced_input.is_low());
 }
+
+#[test]
+fn debounced_input_try_new() {
+    let state_results = [Err("Some error")];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    assert_eq!(
+        TestDebouncedInput::try_new(input_switch).err(),
+        Some(Error::InputSwitch("Some error"))
+    );
+
+    let state_results = [Ok(true), Ok(true)];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut debounced_input = TestDebouncedInput::try_new(input_switch).unwrap();
+    assert!(debounced_input.is_high());
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::High));
+}
+
+#[test]
+fn debounced_input_unknown_initial_state() {
+    let state_results = [
+        Ok(true),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+    ];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut debounced_input = TestDebouncedInput::new_unknown(input_switch);
+    assert!(!debounced_input.is_settled());
+
+    for _ in 0..5 {
+        assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+        assert!(!debounced_input.is_settled());
+        assert!(!debounced_input.is_high());
+    }
+
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::High));
+    assert!(debounced_input.is_settled());
+    assert!(debounced_input.is_high());
+
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::High));
+    assert!(debounced_input.is_settled());
+}
+
+debounced_input_config!(
+    TestTolerantDebouncedInputConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(3),
+    max_read_errors: 2
+);
+
+#[test]
+fn debounced_input_read_errors_tolerance() {
+    let state_results = [
+        Ok(false),
+        Ok(true),
+        Err("Some error"),
+        Ok(true),
+        Err("Some error"),
+        Err("Some error"),
+        Err("Some error"),
+        Ok(false),
+    ];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut debounced_input =
+        DebouncedInput::<_, TestTolerantDebouncedInputConfig>::new(input_switch);
+
+    for read_errors in [0, 1, 0, 1, 2] {
+        assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+        assert_eq!(debounced_input.read_errors(), read_errors);
+    }
+
+    assert_eq!(
+        debounced_input.update(),
+        Err(Error::InputSwitch("Some error"))
+    );
+
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(debounced_input.read_errors(), 0);
+}
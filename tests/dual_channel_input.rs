@@ -0,0 +1,172 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    dual_channel_input_config, Control, DualChannelInput, DualChannelInputError,
+    DualChannelInputEvent,
+};
+use timestamp_source::Timer;
+
+dual_channel_input_config!(
+    TestDualChannelInputConfig,
+    discrepancy_timer: Timer<MockTimestamp> = Timer::new(2),
+    safe_level_a: true,
+    safe_level_b: false
+);
+
+type TestDualChannelInput<SwitchA, SwitchB> =
+    DualChannelInput<SwitchA, SwitchB, TestDualChannelInputConfig>;
+
+#[test]
+fn dual_channel_input_trip() {
+    let state_results_a = [Ok(true), Ok(true), Ok(false), Ok(false), Ok(true)];
+    let state_results_b = [Ok(false), Ok(false), Ok(true), Ok(true), Ok(false)];
+
+    let mut dual_channel_input = TestDualChannelInput::new(
+        MockInputSwitch::new(&state_results_a),
+        MockInputSwitch::new(&state_results_b),
+    );
+
+    assert!(!dual_channel_input.is_safe());
+
+    for _ in 0..2 {
+        assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+        assert!(dual_channel_input.is_safe());
+    }
+
+    for _ in 0..2 {
+        assert_eq!(
+            dual_channel_input.update(),
+            Ok(DualChannelInputEvent::Tripped)
+        );
+        assert!(!dual_channel_input.is_safe());
+    }
+
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+}
+
+#[test]
+fn dual_channel_input_discrepancy() {
+    let state_results_a = [Ok(true), Ok(true), Ok(true), Ok(true), Ok(true), Ok(true)];
+    let state_results_b = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let mut dual_channel_input = TestDualChannelInput::new(
+        MockInputSwitch::new(&state_results_a),
+        MockInputSwitch::new(&state_results_b),
+    );
+
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+
+    for _ in 0..2 {
+        assert_eq!(
+            dual_channel_input.update(),
+            Ok(DualChannelInputEvent::Tripped)
+        );
+        assert!(!dual_channel_input.is_fault());
+    }
+
+    for _ in 0..2 {
+        assert_eq!(
+            dual_channel_input.update(),
+            Ok(DualChannelInputEvent::Discrepancy)
+        );
+        assert!(dual_channel_input.is_fault());
+        assert!(!dual_channel_input.is_safe());
+    }
+
+    dual_channel_input.reset();
+    assert!(!dual_channel_input.is_fault());
+
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+}
+
+#[test]
+fn dual_channel_input_channels_close_apart() {
+    let state_results_a = [Ok(false), Ok(true), Ok(true), Ok(true), Ok(true)];
+    let state_results_b = [Ok(true), Ok(true), Ok(false), Ok(false), Ok(false)];
+
+    let mut dual_channel_input = TestDualChannelInput::new(
+        MockInputSwitch::new(&state_results_a),
+        MockInputSwitch::new(&state_results_b),
+    );
+
+    assert_eq!(
+        dual_channel_input.update(),
+        Ok(DualChannelInputEvent::Tripped)
+    );
+
+    // channel A is safe a tick before channel B
+    assert_eq!(
+        dual_channel_input.update(),
+        Ok(DualChannelInputEvent::Tripped)
+    );
+
+    for _ in 0..3 {
+        assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+        assert!(!dual_channel_input.is_fault());
+    }
+}
+
+#[test]
+fn dual_channel_input_disagreement_requires_trip() {
+    let state_results_a = [Ok(true), Ok(true), Ok(true), Ok(false), Ok(true)];
+    let state_results_b = [Ok(false), Ok(true), Ok(false), Ok(true), Ok(false)];
+
+    let mut dual_channel_input = TestDualChannelInput::new(
+        MockInputSwitch::new(&state_results_a),
+        MockInputSwitch::new(&state_results_b),
+    );
+
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+
+    // both channels are safe again, but they must be seen tripped after the disagreement
+    for _ in 0..2 {
+        assert_eq!(
+            dual_channel_input.update(),
+            Ok(DualChannelInputEvent::Tripped)
+        );
+        assert!(!dual_channel_input.is_safe());
+    }
+
+    assert_eq!(
+        dual_channel_input.update(),
+        Ok(DualChannelInputEvent::Tripped)
+    );
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+    assert!(!dual_channel_input.is_fault());
+}
+
+#[test]
+fn dual_channel_input_error() {
+    let state_results_a = [Ok(true), Err("Some error A"), Ok(true), Ok(true)];
+    let state_results_b = [Ok(false), Ok(false), Err("Some error B"), Ok(false)];
+
+    let mut dual_channel_input = TestDualChannelInput::new(
+        MockInputSwitch::new(&state_results_a),
+        MockInputSwitch::new(&state_results_b),
+    );
+
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+
+    assert_eq!(
+        dual_channel_input.update(),
+        Err(DualChannelInputError::InputSwitchA("Some error A"))
+    );
+    assert!(!dual_channel_input.is_safe());
+
+    assert_eq!(
+        dual_channel_input.update(),
+        Err(DualChannelInputError::InputSwitchB("Some error B"))
+    );
+    assert!(!dual_channel_input.is_safe());
+
+    assert_eq!(dual_channel_input.update(), Ok(DualChannelInputEvent::Safe));
+}
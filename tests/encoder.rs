@@ -2,7 +2,9 @@ mod common;
 
 use crate::common::{MockInputSwitch, MockTimestamp};
 
-use embedded_controls::{encoder_config, Control, Encoder, EncoderEvent, Error};
+use embedded_controls::{
+    encoder_config, AnyOf, CombinedSwitchError, Control, Encoder, EncoderError, EncoderEvent,
+};
 use timestamp_source::Timer;
 
 encoder_config!(
@@ -103,7 +105,62 @@ fn encoder_error() {
     let input_switch_b = MockInputSwitch::new(&state_results_b);
     let mut encoder = TestEncoder::new(input_switch_a, input_switch_b);
 
-    assert_eq!(encoder.update(), Err(Error::InputSwitch("Some error 0")));
-    assert_eq!(encoder.update(), Err(Error::InputSwitch("Some error 1")));
+    assert_eq!(
+        encoder.update(),
+        Err(EncoderError::InputSwitchA("Some error 0"))
+    );
+    assert_eq!(
+        encoder.update(),
+        Err(EncoderError::InputSwitchB("Some error 1"))
+    );
     assert_eq!(encoder.update(), Ok(EncoderEvent::NoTurn));
 }
+
+#[test]
+fn encoder_try_new() {
+    let state_results_a = [Ok(false)];
+    let state_results_b = [Err("Some error 1")];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    assert_eq!(
+        TestEncoder::try_new(input_switch_a, input_switch_b).err(),
+        Some(EncoderError::InputSwitchB("Some error 1"))
+    );
+
+    let state_results_a = [Ok(true), Ok(true), Ok(true)];
+    let state_results_b = [Ok(true), Ok(true), Ok(true)];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = MockInputSwitch::new(&state_results_b);
+    let mut encoder = TestEncoder::new_unknown(input_switch_a, input_switch_b);
+
+    for _ in 0..3 {
+        assert_eq!(encoder.update(), Ok(EncoderEvent::NoTurn));
+    }
+}
+
+#[test]
+fn encoder_mixed_error_types() {
+    let state_results_a = [Ok(false), Err("Some error 0"), Ok(false)];
+    let state_results_b_0 = [Ok(false), Ok(false)];
+    let state_results_b_1 = [Ok(false), Err("Some error 1")];
+
+    let input_switch_a = MockInputSwitch::new(&state_results_a);
+    let input_switch_b = AnyOf::new(
+        MockInputSwitch::new(&state_results_b_0),
+        MockInputSwitch::new(&state_results_b_1),
+    );
+    let mut encoder = TestEncoder::new(input_switch_a, input_switch_b);
+
+    assert_eq!(
+        encoder.update(),
+        Err(EncoderError::InputSwitchA("Some error 0"))
+    );
+    assert_eq!(
+        encoder.update(),
+        Err(EncoderError::InputSwitchB(CombinedSwitchError::B(
+            "Some error 1"
+        )))
+    );
+}
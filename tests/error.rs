@@ -0,0 +1,21 @@
+use embedded_controls::{Error, ErrorKind};
+
+#[test]
+fn error_kind_and_display() {
+    let timer_error: Error<&str, &str> = Error::ElapsedTimer("overflow");
+    let input_error: Error<&str, &str> = Error::InputSwitch("pin is broken");
+
+    assert_eq!(timer_error.kind(), ErrorKind::ElapsedTimer);
+    assert_eq!(input_error.kind(), ErrorKind::InputSwitch);
+
+    assert_eq!(timer_error.to_string(), "elapsed timer error: overflow");
+    assert_eq!(input_error.to_string(), "input error: pin is broken");
+}
+
+#[test]
+fn error_as_dyn_error() {
+    let error: Box<dyn std::error::Error> =
+        Box::new(Error::<&str, &str>::InputSwitch("pin is broken"));
+
+    assert_eq!(error.to_string(), "input error: pin is broken");
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use crate::common::{MockOutputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    Control, Indicator, IndicatorEvent, IndicatorPattern, IndicatorStep, OutputError,
+};
+use timestamp_source::Timer;
+
+type TestTimer = Timer<MockTimestamp>;
+
+const BLINK: IndicatorPattern<TestTimer> = IndicatorPattern::Repeat(&[
+    IndicatorStep::new(true, Timer::new(2)),
+    IndicatorStep::new(false, Timer::new(1)),
+]);
+
+const FLASH: IndicatorPattern<TestTimer> =
+    IndicatorPattern::Once(&[IndicatorStep::new(true, Timer::new(2))]);
+
+#[test]
+fn indicator_blink() {
+    let mut indicator = Indicator::new(MockOutputSwitch::new());
+    indicator.play(BLINK);
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(true)));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+    assert!(indicator.borrow_output_switch().is_on());
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(false)));
+    assert!(!indicator.borrow_output_switch().is_on());
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(true)));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(false)));
+}
+
+#[test]
+fn indicator_one_shot() {
+    let mut indicator = Indicator::new(MockOutputSwitch::new());
+    indicator.play(IndicatorPattern::Steady(false));
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(false)));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+
+    indicator.play(FLASH);
+    assert!(indicator.is_playing_once());
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(true)));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Finished));
+    assert!(!indicator.is_playing_once());
+    assert!(!indicator.is_on());
+    assert!(!indicator.borrow_output_switch().is_on());
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+
+    indicator.play(IndicatorPattern::Once(&[]));
+    assert!(!indicator.is_playing_once());
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+}
+
+#[test]
+fn indicator_error() {
+    let results = [Ok(()), Ok(()), Err("Some error"), Ok(())];
+    let mut indicator = Indicator::new(MockOutputSwitch::with_results(&results));
+    indicator.play(IndicatorPattern::Steady(false));
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(false)));
+
+    indicator.play(FLASH);
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Switched(true)));
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+
+    assert_eq!(
+        indicator.update(),
+        Err(OutputError::OutputSwitch("Some error"))
+    );
+    assert!(!indicator.is_playing_once());
+    assert!(indicator.is_on());
+    assert!(indicator.borrow_output_switch().is_on());
+
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::Finished));
+    assert!(!indicator.is_on());
+    assert!(!indicator.borrow_output_switch().is_on());
+    assert_eq!(indicator.update(), Ok(IndicatorEvent::NoChange));
+}
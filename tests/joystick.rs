@@ -0,0 +1,198 @@
+mod common;
+
+use crate::common::{MockAnalogInput, MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    joystick_config, Control, Joystick, JoystickAxisScale, JoystickDirection, JoystickDirections,
+    JoystickError, JoystickEvent,
+};
+use timestamp_source::Timer;
+
+joystick_config!(
+    TestJoystickConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1),
+    x_scale: JoystickAxisScale { multiplier: 1, divisor: 1 },
+    y_scale: JoystickAxisScale { multiplier: 1, divisor: 1 },
+    dead_zone: 100,
+    dead_zone_hysteresis: 20,
+    directions: JoystickDirections::Eight,
+    direction_hysteresis: 20,
+    repeat_delay_timer: Timer<MockTimestamp> = Timer::new(4),
+    repeat_interval_timer: Timer<MockTimestamp> = Timer::new(2)
+);
+
+joystick_config!(
+    TestScaledJoystickConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1),
+    x_scale: JoystickAxisScale { multiplier: 10_000_000, divisor: 1 },
+    y_scale: JoystickAxisScale { multiplier: -10_000_000, divisor: 3 },
+    dead_zone: 100,
+    dead_zone_hysteresis: 20,
+    directions: JoystickDirections::Four,
+    direction_hysteresis: 20,
+    repeat_delay_timer: Timer<MockTimestamp> = Timer::new(4),
+    repeat_interval_timer: Timer<MockTimestamp> = Timer::new(2)
+);
+
+type TestJoystick<Axis, Switch> = Joystick<Axis, Switch, TestJoystickConfig>;
+type TestScaledJoystick<Axis, Switch> = Joystick<Axis, Switch, TestScaledJoystickConfig>;
+
+#[test]
+fn joystick_success() {
+    let value_results_x = [
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(662),
+        Ok(572),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+    ];
+
+    let value_results_y = [
+        Ok(512),
+        Ok(700),
+        Ok(700),
+        Ok(700),
+        Ok(700),
+        Ok(700),
+        Ok(700),
+        Ok(600),
+        Ok(625),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+        Ok(512),
+    ];
+
+    let state_results = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let mut joystick = TestJoystick::new(
+        MockAnalogInput::new(&value_results_x),
+        MockAnalogInput::new(&value_results_y),
+        MockInputSwitch::new(&state_results),
+    );
+
+    assert_eq!(joystick.center(), (512, 512));
+
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Pressed(JoystickDirection::Up))
+    );
+    assert_eq!(joystick.position(), (0, 188));
+    assert_eq!(joystick.update(), Ok(JoystickEvent::NoEvent));
+    for _ in 0..2 {
+        assert_eq!(
+            joystick.update(),
+            Ok(JoystickEvent::Repeated(JoystickDirection::Up))
+        );
+    }
+
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Released(JoystickDirection::Up))
+    );
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Pressed(JoystickDirection::UpRight))
+    );
+    assert_eq!(joystick.direction(), Some(JoystickDirection::UpRight));
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Released(JoystickDirection::UpRight))
+    );
+    assert_eq!(joystick.direction(), None);
+
+    for _ in 0..2 {
+        assert_eq!(joystick.update(), Ok(JoystickEvent::NoEvent));
+    }
+
+    assert_eq!(joystick.update(), Ok(JoystickEvent::ButtonPressed));
+    assert!(joystick.is_button_pressed());
+    assert_eq!(joystick.update(), Ok(JoystickEvent::NoEvent));
+    assert_eq!(joystick.update(), Ok(JoystickEvent::ButtonReleased));
+}
+
+#[test]
+fn joystick_button_and_direction_in_one_update() {
+    let value_results_x = [Ok(512), Ok(512), Ok(512), Ok(512), Ok(512)];
+    let value_results_y = [Ok(512), Ok(512), Ok(700), Ok(700), Ok(700)];
+    let state_results = [Ok(false), Ok(true), Ok(true), Ok(true), Ok(true)];
+
+    let mut joystick = TestJoystick::new(
+        MockAnalogInput::new(&value_results_x),
+        MockAnalogInput::new(&value_results_y),
+        MockInputSwitch::new(&state_results),
+    );
+
+    assert_eq!(joystick.update(), Ok(JoystickEvent::NoEvent));
+    assert_eq!(joystick.update(), Ok(JoystickEvent::ButtonPressed));
+    assert_eq!(joystick.direction(), Some(JoystickDirection::Up));
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Pressed(JoystickDirection::Up))
+    );
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Repeated(JoystickDirection::Up))
+    );
+}
+
+#[test]
+fn joystick_large_scale() {
+    let value_results_x = [Ok(512), Ok(1023)];
+    let value_results_y = [Ok(512), Ok(0)];
+    let state_results = [Ok(false), Ok(false)];
+
+    let mut joystick = TestScaledJoystick::new(
+        MockAnalogInput::new(&value_results_x),
+        MockAnalogInput::new(&value_results_y),
+        MockInputSwitch::new(&state_results),
+    );
+
+    assert_eq!(
+        joystick.update(),
+        Ok(JoystickEvent::Pressed(JoystickDirection::Right))
+    );
+    assert_eq!(joystick.position(), (i32::MAX, 1_706_666_666));
+}
+
+#[test]
+fn joystick_error() {
+    let value_results_x = [Ok(512), Err("Some error 0"), Ok(512), Ok(512)];
+    let value_results_y = [Ok(512), Ok(512), Ok(512)];
+    let state_results = [Ok(false), Ok(false), Err("Some error 1"), Ok(false)];
+
+    let mut joystick = TestJoystick::new(
+        MockAnalogInput::new(&value_results_x),
+        MockAnalogInput::new(&value_results_y),
+        MockInputSwitch::new(&state_results),
+    );
+
+    assert_eq!(joystick.update(), Err(JoystickError::Axis("Some error 0")));
+    assert_eq!(
+        joystick.update(),
+        Err(JoystickError::Button("Some error 1"))
+    );
+    assert_eq!(joystick.update(), Ok(JoystickEvent::NoEvent));
+}
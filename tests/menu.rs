@@ -0,0 +1,149 @@
+use embedded_controls::{
+    DebouncedInputEvent, EncoderEvent, Menu, MenuEvent, MenuInput, MenuItem, MenuItemKind,
+    MenuValue,
+};
+
+const SETTINGS: &[MenuItem] = &[
+    MenuItem {
+        label: "Volume",
+        kind: MenuItemKind::Number {
+            id: 0,
+            min: 0,
+            max: 10,
+            step: 5,
+        },
+    },
+    MenuItem {
+        label: "Mode",
+        kind: MenuItemKind::Enum {
+            id: 1,
+            options: &["Auto", "Manual"],
+        },
+    },
+    MenuItem {
+        label: "Beep",
+        kind: MenuItemKind::Bool { id: 2 },
+    },
+];
+
+const ROOT: &[MenuItem] = &[
+    MenuItem {
+        label: "Settings",
+        kind: MenuItemKind::Submenu(SETTINGS),
+    },
+    MenuItem {
+        label: "Reset",
+        kind: MenuItemKind::Action(7),
+    },
+];
+
+type TestMenu = Menu<2, 3>;
+
+#[test]
+fn menu_navigation() {
+    let mut menu = TestMenu::new("Main", ROOT, [5, 0, 1]);
+
+    assert_eq!(menu.view().title, "Main");
+    assert_eq!(menu.view().cursor, 0);
+
+    assert_eq!(
+        menu.handle_encoder_event(EncoderEvent::CounterClockwiseTurn),
+        MenuEvent::NoChange
+    );
+    assert_eq!(
+        menu.handle_encoder_event(EncoderEvent::ClockwiseTurn),
+        MenuEvent::CursorMoved(1)
+    );
+    assert_eq!(
+        menu.handle_encoder_event(EncoderEvent::ClockwiseTurn),
+        MenuEvent::NoChange
+    );
+    assert_eq!(
+        menu.handle_enter_event(DebouncedInputEvent::High),
+        MenuEvent::NoChange
+    );
+    assert_eq!(
+        menu.handle_enter_event(DebouncedInputEvent::Rise),
+        MenuEvent::Action(7)
+    );
+
+    assert_eq!(menu.handle(MenuInput::Previous), MenuEvent::CursorMoved(0));
+    assert_eq!(menu.handle(MenuInput::Enter), MenuEvent::Entered);
+    assert_eq!(menu.depth(), 1);
+    assert_eq!(menu.view().title, "Settings");
+    assert_eq!(menu.view().items, SETTINGS);
+
+    assert_eq!(
+        menu.handle_back_event(DebouncedInputEvent::Rise),
+        MenuEvent::Exited
+    );
+    assert_eq!(menu.depth(), 0);
+    assert_eq!(menu.handle(MenuInput::Back), MenuEvent::NoChange);
+}
+
+#[test]
+fn menu_editing() {
+    let mut menu = TestMenu::new("Main", ROOT, [5, 0, 1]);
+
+    assert_eq!(menu.handle(MenuInput::Enter), MenuEvent::Entered);
+
+    assert_eq!(menu.handle(MenuInput::Enter), MenuEvent::EditStarted(0));
+    assert!(menu.is_editing());
+    assert_eq!(
+        menu.handle(MenuInput::Next),
+        MenuEvent::EditChanged { id: 0, value: 10 }
+    );
+    assert_eq!(menu.handle(MenuInput::Next), MenuEvent::NoChange);
+    assert_eq!(menu.view().value(0), Some(MenuValue::Number(10)));
+    assert_eq!(menu.value(0), Some(5));
+    assert_eq!(menu.handle(MenuInput::Back), MenuEvent::EditCancelled(0));
+    assert_eq!(menu.value(0), Some(5));
+
+    assert_eq!(menu.handle(MenuInput::Enter), MenuEvent::EditStarted(0));
+    assert_eq!(
+        menu.handle(MenuInput::Previous),
+        MenuEvent::EditChanged { id: 0, value: 0 }
+    );
+    assert_eq!(
+        menu.handle(MenuInput::Enter),
+        MenuEvent::ValueChanged { id: 0, value: 0 }
+    );
+    assert!(!menu.is_editing());
+
+    assert_eq!(menu.handle(MenuInput::Next), MenuEvent::CursorMoved(1));
+    assert_eq!(menu.view().value(1), Some(MenuValue::Option("Auto")));
+    assert_eq!(menu.handle(MenuInput::Enter), MenuEvent::EditStarted(1));
+    assert_eq!(
+        menu.handle(MenuInput::Next),
+        MenuEvent::EditChanged { id: 1, value: 1 }
+    );
+    assert_eq!(menu.handle(MenuInput::Next), MenuEvent::NoChange);
+    assert_eq!(
+        menu.handle(MenuInput::Enter),
+        MenuEvent::ValueChanged { id: 1, value: 1 }
+    );
+    assert_eq!(menu.view().value(1), Some(MenuValue::Option("Manual")));
+
+    assert_eq!(menu.handle(MenuInput::Next), MenuEvent::CursorMoved(2));
+    assert_eq!(
+        menu.handle(MenuInput::Enter),
+        MenuEvent::ValueChanged { id: 2, value: 0 }
+    );
+    assert_eq!(menu.view().value(2), Some(MenuValue::Bool(false)));
+
+    assert_eq!(menu.values(), &[0, 1, 0]);
+}
+
+#[test]
+fn menu_visible_range() {
+    let mut menu = TestMenu::new("Main", ROOT, [5, 0, 1]);
+
+    menu.handle(MenuInput::Enter);
+
+    assert_eq!(menu.view().visible_range(2), 0..2);
+    menu.handle(MenuInput::Next);
+    assert_eq!(menu.view().visible_range(2), 0..2);
+    menu.handle(MenuInput::Next);
+    assert_eq!(menu.view().visible_range(2), 1..3);
+    assert_eq!(menu.view().visible_range(5), 0..3);
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use crate::common::{MockOutputSwitch, MockTimestamp};
+
+use embedded_controls::{output_config, Control, Output, OutputError, OutputEvent};
+use timestamp_source::Timer;
+
+output_config!(
+    TestOutputConfig,
+    min_on_timer: Timer<MockTimestamp> = Timer::new(3),
+    min_off_timer: Timer<MockTimestamp> = Timer::new(4)
+);
+
+output_config!(
+    TestLimitedOutputConfig,
+    min_on_timer: Timer<MockTimestamp> = Timer::new(1),
+    min_off_timer: Timer<MockTimestamp> = Timer::new(1),
+    max_on_timer: Timer<MockTimestamp> = Timer::new(5)
+);
+
+type TestOutput<Switch> = Output<Switch, TestOutputConfig>;
+type TestLimitedOutput<Switch> = Output<Switch, TestLimitedOutputConfig>;
+
+#[test]
+fn output_min_on_off_times() {
+    let mut output = TestOutput::new(MockOutputSwitch::new());
+
+    assert_eq!(output.update(), Ok(OutputEvent::Steady));
+
+    output.set(true);
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(true)));
+    assert!(output.is_on());
+    assert!(output.borrow_output_switch().is_on());
+
+    output.set(false);
+    for _ in 0..2 {
+        assert_eq!(output.update(), Ok(OutputEvent::Deferred));
+        assert!(output.borrow_output_switch().is_on());
+    }
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(false)));
+    assert!(!output.borrow_output_switch().is_on());
+
+    output.set(true);
+    for _ in 0..3 {
+        assert_eq!(output.update(), Ok(OutputEvent::Deferred));
+        assert!(!output.is_on());
+    }
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(true)));
+    assert_eq!(output.update(), Ok(OutputEvent::Steady));
+}
+
+#[test]
+fn output_max_on_time() {
+    let mut output = TestLimitedOutput::new(MockOutputSwitch::new());
+
+    output.set(true);
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(true)));
+
+    for _ in 0..4 {
+        assert_eq!(output.update(), Ok(OutputEvent::Steady));
+    }
+
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(false)));
+    assert!(!output.is_requested());
+    assert!(!output.borrow_output_switch().is_on());
+
+    assert_eq!(output.update(), Ok(OutputEvent::Steady));
+}
+
+#[test]
+fn output_try_new() {
+    let results = [Err("Some error 0")];
+
+    assert_eq!(
+        TestOutput::try_new(MockOutputSwitch::with_results(&results)).err(),
+        Some(OutputError::OutputSwitch("Some error 0"))
+    );
+
+    let mut output = TestOutput::try_new(MockOutputSwitch::new()).unwrap();
+    assert!(!output.borrow_output_switch().is_on());
+    assert_eq!(output.update(), Ok(OutputEvent::Steady));
+}
+
+#[test]
+fn output_error() {
+    let results = [
+        Err("Some error 0"),
+        Ok(()),
+        Err("Some error 1"),
+        Ok(()),
+        Err("Some error 2"),
+        Ok(()),
+    ];
+    let mut output = TestLimitedOutput::new(MockOutputSwitch::with_results(&results));
+
+    // switching off upon creation is retried before the request is applied
+    output.set(true);
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(false)));
+    assert!(!output.is_on());
+
+    assert_eq!(
+        output.update(),
+        Err(OutputError::OutputSwitch("Some error 1"))
+    );
+    assert!(!output.is_on());
+    assert!(!output.borrow_output_switch().is_on());
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(true)));
+
+    output.set(false);
+    assert_eq!(
+        output.update(),
+        Err(OutputError::OutputSwitch("Some error 2"))
+    );
+    assert!(output.is_on());
+    assert!(output.borrow_output_switch().is_on());
+    assert_eq!(output.update(), Ok(OutputEvent::Switched(false)));
+    assert!(!output.borrow_output_switch().is_on());
+}
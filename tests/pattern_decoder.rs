@@ -0,0 +1,143 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    pattern_decoder_config, Control, Error, PatternDecoder, PatternDecoderEvent, PressPattern,
+};
+use timestamp_source::Timer;
+
+pattern_decoder_config!(
+    TestPatternDecoderConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1),
+    long_press_timer: Timer<MockTimestamp> = Timer::new(6),
+    gap_timer: Timer<MockTimestamp> = Timer::new(8)
+);
+
+type TestPatternDecoder<Switch> = PatternDecoder<Switch, TestPatternDecoderConfig>;
+
+#[test]
+fn press_pattern() {
+    let pattern = PressPattern::from_morse(".-").unwrap();
+
+    assert_eq!(pattern, PressPattern::new(0b10, 2));
+    assert_eq!(pattern.bits(), 0b10);
+    assert_eq!(pattern.len(), 2);
+    assert_eq!(pattern.is_long(0), Some(false));
+    assert_eq!(pattern.is_long(1), Some(true));
+    assert_eq!(pattern.is_long(2), None);
+    assert_eq!(pattern.to_morse_char(), Some('A'));
+
+    assert_eq!(
+        PressPattern::from_morse("...---...")
+            .unwrap()
+            .to_morse_char(),
+        None
+    );
+    assert_eq!(PressPattern::from_morse("..x"), None);
+    assert_eq!(
+        PressPattern::from_morse("----.").unwrap().to_morse_char(),
+        Some('9')
+    );
+}
+
+#[test]
+fn pattern_decoder_success() {
+    let state_results = [
+        Ok(false),
+        // short press
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        // long press
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        // gap
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut pattern_decoder = TestPatternDecoder::new(input_switch);
+
+    for _ in 0..13 {
+        assert_eq!(pattern_decoder.update(), Ok(PatternDecoderEvent::NoPattern));
+    }
+    assert_eq!(
+        pattern_decoder.pending_pattern(),
+        PressPattern::from_morse(".-").unwrap()
+    );
+
+    let event = pattern_decoder.update();
+    assert_eq!(
+        event,
+        Ok(PatternDecoderEvent::Pattern(
+            PressPattern::from_morse(".-").unwrap()
+        ))
+    );
+    assert!(pattern_decoder.pending_pattern().is_empty());
+
+    assert_eq!(pattern_decoder.update(), Ok(PatternDecoderEvent::NoPattern));
+}
+
+#[test]
+fn pattern_decoder_error_keeps_pattern() {
+    let state_results = [
+        Ok(false),
+        // short press
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        // read error between presses
+        Err("Some error"),
+        // long press
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        // gap
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut pattern_decoder = TestPatternDecoder::new(input_switch);
+
+    for _ in 0..4 {
+        assert_eq!(pattern_decoder.update(), Ok(PatternDecoderEvent::NoPattern));
+    }
+
+    assert_eq!(
+        pattern_decoder.update(),
+        Err(Error::InputSwitch("Some error"))
+    );
+    assert_eq!(
+        pattern_decoder.pending_pattern(),
+        PressPattern::from_morse(".").unwrap()
+    );
+
+    for _ in 0..9 {
+        assert_eq!(pattern_decoder.update(), Ok(PatternDecoderEvent::NoPattern));
+    }
+
+    assert_eq!(
+        pattern_decoder.update(),
+        Ok(PatternDecoderEvent::Pattern(
+            PressPattern::from_morse(".-").unwrap()
+        ))
+    );
+}
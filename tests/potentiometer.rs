@@ -0,0 +1,116 @@
+mod common;
+
+use crate::common::MockAnalogInput;
+
+use embedded_controls::{
+    potentiometer_config, Control, Potentiometer, PotentiometerEvent, PotentiometerSmoothing,
+};
+
+potentiometer_config!(
+    TestPotentiometerConfig,
+    max_value: 1023,
+    positions: 10,
+    smoothing: PotentiometerSmoothing::None,
+    dead_band: 12,
+    hysteresis: 10
+);
+
+potentiometer_config!(
+    TestSmoothPotentiometerConfig,
+    max_value: 1023,
+    positions: 10,
+    smoothing: PotentiometerSmoothing::Exponential(1),
+    dead_band: 12,
+    hysteresis: 10
+);
+
+potentiometer_config!(
+    TestAveragePotentiometerConfig,
+    max_value: 1023,
+    positions: 10,
+    smoothing: PotentiometerSmoothing::MovingAverage(2),
+    dead_band: 12,
+    hysteresis: 10
+);
+
+type TestPotentiometer<Input> = Potentiometer<Input, TestPotentiometerConfig>;
+type TestSmoothPotentiometer<Input> = Potentiometer<Input, TestSmoothPotentiometerConfig>;
+type TestAveragePotentiometer<Input> = Potentiometer<Input, TestAveragePotentiometerConfig>;
+
+#[test]
+fn potentiometer_success() {
+    let value_results = [
+        Ok(112),
+        Ok(195),
+        Ok(215),
+        Ok(222),
+        Ok(205),
+        Ok(185),
+        Ok(0),
+        Ok(1023),
+        Ok(1015),
+    ];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut potentiometer = TestPotentiometer::new(analog_input);
+
+    assert_eq!(potentiometer.value(), 1);
+
+    for _ in 0..2 {
+        assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::NoChange));
+    }
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(2)));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::NoChange));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(1)));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(0)));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(9)));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::NoChange));
+    assert_eq!(potentiometer.value(), 9);
+}
+
+#[test]
+fn potentiometer_smoothing() {
+    let value_results = [Ok(0), Ok(1000), Ok(1000), Ok(1000), Ok(1000)];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut potentiometer = TestSmoothPotentiometer::new(analog_input);
+
+    assert_eq!(potentiometer.value(), 0);
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(4)));
+    assert_eq!(potentiometer.raw_value(), 500);
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(7)));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(8)));
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(9)));
+}
+
+#[test]
+fn potentiometer_moving_average() {
+    let value_results = [Ok(0), Ok(1000), Ok(1000), Ok(1000)];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut potentiometer = TestAveragePotentiometer::new(analog_input);
+
+    assert_eq!(potentiometer.value(), 0);
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(4)));
+    assert_eq!(potentiometer.raw_value(), 500);
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(9)));
+    assert_eq!(potentiometer.raw_value(), 1000);
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::NoChange));
+}
+
+#[test]
+fn potentiometer_error_keeps_value() {
+    let value_results = [Ok(0), Ok(1000), Err("Some error"), Ok(1000)];
+
+    let analog_input = MockAnalogInput::new(&value_results);
+    let mut potentiometer = TestSmoothPotentiometer::new(analog_input);
+
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(4)));
+
+    // the filter and the value are kept until the next good read
+    assert_eq!(potentiometer.update(), Err("Some error"));
+    assert_eq!(potentiometer.value(), 4);
+    assert_eq!(potentiometer.raw_value(), 500);
+
+    assert_eq!(potentiometer.update(), Ok(PotentiometerEvent::Changed(7)));
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    debounced_input_config, encoder_config, Control, DebouncedInput, DebouncedInputEvent, Encoder,
+    EncoderEvent,
+};
+use proptest::prelude::*;
+use timestamp_source::Timer;
+
+const DEBOUNCE: usize = 3;
+
+debounced_input_config!(
+    TestDebouncedInputConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(DEBOUNCE as u32)
+);
+
+encoder_config!(
+    TestEncoderConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1),
+    counts_div: i8 = 1
+);
+
+type TestDebouncedInput<Switch> = DebouncedInput<Switch, TestDebouncedInputConfig>;
+type TestEncoder<SwitchA, SwitchB> = Encoder<SwitchA, SwitchB, TestEncoderConfig>;
+
+const QUADRATURE: [(bool, bool); 4] = [(false, false), (true, false), (true, true), (false, true)];
+
+fn debounced_input_events(levels: &[bool]) -> Vec<DebouncedInputEvent> {
+    let state_results: Vec<_> = levels.iter().copied().map(Ok).collect();
+    let mut debounced_input = TestDebouncedInput::new(MockInputSwitch::new(&state_results));
+
+    (1..levels.len())
+        .map(|_| debounced_input.update().unwrap())
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn edges_require_stable_level(levels in prop::collection::vec(any::<bool>(), 1..200)) {
+        let events = debounced_input_events(&levels);
+
+        for (index, event) in events.iter().enumerate() {
+            let read = index + 1;
+            let expected_level = match event {
+                DebouncedInputEvent::Rise => true,
+                DebouncedInputEvent::Fall => false,
+                _ => continue,
+            };
+
+            prop_assert!(read > DEBOUNCE);
+            prop_assert!(levels[read - DEBOUNCE..=read]
+                .iter()
+                .all(|level| *level == expected_level));
+        }
+    }
+
+    #[test]
+    fn edges_alternate(levels in prop::collection::vec(any::<bool>(), 1..200)) {
+        let events = debounced_input_events(&levels);
+        let mut high = levels[0];
+
+        for event in events {
+            match event {
+                DebouncedInputEvent::Rise => {
+                    prop_assert!(!high);
+                    high = true;
+                }
+                DebouncedInputEvent::Fall => {
+                    prop_assert!(high);
+                    high = false;
+                }
+                DebouncedInputEvent::High => prop_assert!(high),
+                DebouncedInputEvent::Low => prop_assert!(!high),
+            }
+        }
+
+        if levels.len() > DEBOUNCE + 1 {
+            let last = levels[levels.len() - 1];
+            if levels[levels.len() - DEBOUNCE - 1..].iter().all(|level| *level == last) {
+                prop_assert_eq!(high, last);
+            }
+        }
+    }
+
+    #[test]
+    fn encoder_net_count_equals_rotation(
+        steps in prop::collection::vec(any::<bool>(), 0..50),
+        hold in 3usize..6,
+    ) {
+        let mut phase = 0usize;
+        let mut levels = vec![QUADRATURE[phase]];
+
+        for clockwise in &steps {
+            phase = if *clockwise { (phase + 1) % 4 } else { (phase + 3) % 4 };
+            levels.extend(std::iter::repeat(QUADRATURE[phase]).take(hold));
+        }
+        levels.extend(std::iter::repeat(QUADRATURE[phase]).take(hold));
+
+        let state_results_a: Vec<_> = levels.iter().map(|(a, _)| Ok(*a)).collect();
+        let state_results_b: Vec<_> = levels.iter().map(|(_, b)| Ok(*b)).collect();
+        let mut encoder = TestEncoder::new(
+            MockInputSwitch::new(&state_results_a),
+            MockInputSwitch::new(&state_results_b),
+        );
+
+        let mut net = 0i64;
+        for _ in 1..levels.len() {
+            match encoder.update().unwrap() {
+                EncoderEvent::ClockwiseTurn => net += 1,
+                EncoderEvent::CounterClockwiseTurn => net -= 1,
+                EncoderEvent::NoTurn => {}
+            }
+        }
+
+        let rotation: i64 = steps.iter().map(|clockwise| if *clockwise { 1 } else { -1 }).sum();
+        prop_assert_eq!(net, rotation);
+    }
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockOutputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    debounced_input_config, decode_record_entries, touch_key_config, Control, DebouncedInput,
+    DebouncedInputEvent, Error, RecordEntry, Recorder, TouchKey,
+};
+use timestamp_source::Timer;
+
+debounced_input_config!(
+    TestDebouncedInputConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(2)
+);
+
+type TestDebouncedInput<Switch> = DebouncedInput<Switch, TestDebouncedInputConfig>;
+
+touch_key_config!(
+    TestTouchKeyConfig,
+    measure_timeout_timer: Timer<MockTimestamp> = Timer::new(10),
+    touch_threshold: 5,
+    release_threshold: 3,
+    baseline_shift: 2
+);
+
+type TestTouchKey<Output, Input> = TouchKey<Output, Input, TestTouchKeyConfig>;
+
+fn entry(ticks: u32, raw_levels: u8, event_code: u8) -> RecordEntry {
+    RecordEntry {
+        ticks,
+        raw_levels,
+        event_code,
+    }
+}
+
+#[test]
+fn recorder_ring_buffer() {
+    let state_results = [
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Err("Some error"),
+        Ok(false),
+    ];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut recorder = Recorder::<_, MockTimestamp, 4>::new(TestDebouncedInput::new(input_switch));
+
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::Rise));
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::High));
+    assert_eq!(recorder.update(), Err(Error::InputSwitch("Some error")));
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::High));
+
+    assert_eq!(recorder.len(), 4);
+    assert_eq!(
+        recorder.entries().copied().collect::<Vec<_>>(),
+        [
+            entry(4, 1, 0),
+            entry(6, 1, 2),
+            entry(9, 1, RecordEntry::ERROR_EVENT_CODE),
+            entry(11, 0, 1)
+        ]
+    );
+    assert_eq!(
+        recorder.entries().nth(1).unwrap().event(),
+        Some(DebouncedInputEvent::Rise)
+    );
+    assert!(recorder.entries().nth(2).unwrap().is_error());
+}
+
+#[test]
+fn recorder_encode_decode() {
+    let state_results = [Ok(false), Ok(true), Ok(false), Ok(true)];
+
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut recorder = Recorder::<_, MockTimestamp, 8>::new(TestDebouncedInput::new(input_switch));
+
+    for _ in 0..3 {
+        recorder.update().unwrap();
+    }
+
+    let mut buffer = [0; 8 * RecordEntry::SIZE];
+    let len = recorder.encode(&mut buffer);
+    assert_eq!(len, 3 * RecordEntry::SIZE);
+    assert_eq!(buffer[..RecordEntry::SIZE], [2, 0, 0, 0, 1, 0]);
+
+    assert!(decode_record_entries(&buffer[..len]).eq(recorder.entries().copied()));
+
+    let mut short_buffer = [0; 2 * RecordEntry::SIZE + 1];
+    assert_eq!(recorder.encode(&mut short_buffer), 2 * RecordEntry::SIZE);
+
+    recorder.clear();
+    assert!(recorder.is_empty());
+}
+
+#[test]
+fn recorder_without_raw_levels() {
+    let state_results = [
+        Ok(false),
+        Ok(true),
+        Ok(false),
+        Ok(true),
+        Err("Some error"),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+    ];
+
+    let touch_key = TestTouchKey::new(
+        MockOutputSwitch::new(),
+        MockInputSwitch::new(&state_results),
+    );
+    let mut recorder = Recorder::<_, MockTimestamp, 4>::without_raw_levels(touch_key);
+
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::Low));
+    assert!(recorder.update().is_err());
+    assert_eq!(recorder.update(), Ok(DebouncedInputEvent::Rise));
+
+    assert_eq!(
+        recorder
+            .entries()
+            .map(|entry| (entry.raw_levels, entry.event_code))
+            .collect::<Vec<_>>(),
+        [(0, 0), (0, RecordEntry::ERROR_EVENT_CODE), (0, 2)]
+    );
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "replay")]
+
+use embedded_controls::{
+    replay::{
+        format_ticks, parse_duration, replay_debounced_input, replay_encoder, ReplayError,
+        ReplayTimer, Trace,
+    },
+    DebouncedInputEvent, EncoderEvent, RecordEntry,
+};
+
+const VCD: &str = "\
+$timescale 1 us $end
+$scope module top $end
+$var wire 1 ! btn $end
+$var wire 8 \" bus $end
+$upscope $end
+$enddefinitions $end
+$dumpvars
+0!
+b00000000 \"
+$end
+#1000
+1!
+#1200
+0!
+#1500
+1!
+#40000
+0!
+";
+
+const CSV: &str = "\
+Time [s],A,B
+0.000,0,0
+0.010,1,0
+0.020,1,1
+0.030,0,1
+0.040,0,0
+0.050,0,1
+0.060,1,1
+0.070,1,0
+0.080,0,0
+";
+
+#[test]
+fn replay_vcd_debounced_input() {
+    let trace = Trace::parse_vcd(VCD).unwrap();
+
+    assert_eq!(trace.names(), ["btn"]);
+    assert_eq!(
+        trace.waveform("btn").unwrap(),
+        [
+            (0, false),
+            (1_000_000, true),
+            (1_200_000, false),
+            (1_500_000, true),
+            (40_000_000, false)
+        ]
+    );
+    assert_eq!(
+        trace.waveform("bus"),
+        Err(ReplayError::UnknownSignal("bus".to_string()))
+    );
+
+    ReplayTimer::set_duration(parse_duration("5ms").unwrap());
+    let events =
+        replay_debounced_input(trace.waveform("btn").unwrap(), 1_000_000, 50_000_000).unwrap();
+
+    assert_eq!(
+        events,
+        [
+            (6_000_000, DebouncedInputEvent::Rise),
+            (45_000_000, DebouncedInputEvent::Fall)
+        ]
+    );
+    assert_eq!(format_ticks(events[0].0), "0.006000000");
+}
+
+#[test]
+fn replay_csv_encoder() {
+    let trace = Trace::parse_csv(CSV).unwrap();
+
+    ReplayTimer::set_duration(parse_duration("2ms").unwrap());
+    let a = trace.waveform("A").unwrap();
+    let b = trace.waveform("B").unwrap();
+
+    assert_eq!(
+        replay_encoder(a, b, 4, 1_000_000, 100_000_000),
+        Ok(vec![
+            (42_000_000, EncoderEvent::ClockwiseTurn),
+            (82_000_000, EncoderEvent::CounterClockwiseTurn)
+        ])
+    );
+    assert_eq!(
+        replay_encoder(a, b, 1, 1_000_000, 100_000_000)
+            .unwrap()
+            .len(),
+        8
+    );
+    assert_eq!(
+        replay_encoder(a, b, 3, 1_000_000, 100_000_000),
+        Err(ReplayError::UnsupportedCountsDiv(3))
+    );
+}
+
+#[test]
+fn replay_parse_errors() {
+    assert_eq!(parse_duration("1.5us"), Some(1_500));
+    assert_eq!(parse_duration("20 ms"), Some(20_000_000));
+    assert_eq!(parse_duration("20"), None);
+
+    assert!(matches!(
+        Trace::parse_csv("t,A\n0.0,2\n"),
+        Err(ReplayError::Parse(2, _))
+    ));
+    assert!(matches!(
+        Trace::parse_vcd("$timescale 1 xs $end\n"),
+        Err(ReplayError::Parse(1, _))
+    ));
+}
+
+#[test]
+fn replay_record_entries() {
+    let entries = [
+        (u32::MAX - 1, 0b00, 0),
+        (u32::MAX, 0b01, 0),
+        (3, 0b11, RecordEntry::ERROR_EVENT_CODE),
+        (5, 0b10, 1),
+    ]
+    .map(|(ticks, raw_levels, event_code)| RecordEntry {
+        ticks,
+        raw_levels,
+        event_code,
+    });
+
+    let trace = Trace::from_record_entries(entries, &["A", "B"], 2);
+    let wrap = 2 << u32::BITS;
+
+    assert_eq!(
+        trace.waveform("A").unwrap(),
+        [
+            (2 * (u32::MAX as u64 - 1), false),
+            (2 * u32::MAX as u64, true),
+            (wrap + 10, false)
+        ]
+    );
+    assert_eq!(
+        trace.waveform("B").unwrap(),
+        [(2 * (u32::MAX as u64 - 1), false), (wrap + 10, true)]
+    );
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use crate::common::MockTimestamp;
+
+use embedded_controls::{
+    sequence_matcher_config, EncoderEvent, SequenceMatcher, SequenceMatcherEvent,
+};
+use timestamp_source::{Timer, Timestamp};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Key {
+    Up,
+    Down,
+    Ok,
+}
+
+sequence_matcher_config!(
+    TestSequenceMatcherConfig,
+    event: Key,
+    timeout_timer: Timer<MockTimestamp> = Timer::new(3),
+    patterns: &[
+        &[Key::Up, Key::Up, Key::Down, Key::Down, Key::Ok],
+        &[Key::Ok, Key::Ok],
+    ]
+);
+
+sequence_matcher_config!(
+    TestEncoderSequenceMatcherConfig,
+    event: EncoderEvent,
+    timeout_timer: Timer<MockTimestamp> = Timer::new(3),
+    patterns: &[&[EncoderEvent::ClockwiseTurn, EncoderEvent::CounterClockwiseTurn]],
+    idle_events: &[EncoderEvent::NoTurn]
+);
+
+type TestSequenceMatcher = SequenceMatcher<TestSequenceMatcherConfig, 5>;
+type TestEncoderSequenceMatcher = SequenceMatcher<TestEncoderSequenceMatcherConfig, 2>;
+
+#[test]
+fn sequence_matcher_success() {
+    let mut sequence_matcher = TestSequenceMatcher::new();
+
+    for key in [Key::Up, Key::Up, Key::Up, Key::Down, Key::Down] {
+        assert_eq!(
+            sequence_matcher.push(key),
+            Ok(SequenceMatcherEvent::NoMatch)
+        );
+    }
+
+    assert_eq!(
+        sequence_matcher.push(Key::Ok),
+        Ok(SequenceMatcherEvent::SequenceMatched(0))
+    );
+    assert!(sequence_matcher.is_empty());
+
+    assert_eq!(
+        sequence_matcher.push(Key::Ok),
+        Ok(SequenceMatcherEvent::NoMatch)
+    );
+    assert_eq!(
+        sequence_matcher.push(Key::Ok),
+        Ok(SequenceMatcherEvent::SequenceMatched(1))
+    );
+}
+
+#[test]
+fn sequence_matcher_timeout() {
+    let mut sequence_matcher = TestSequenceMatcher::new();
+
+    for key in [Key::Up, Key::Up, Key::Down] {
+        assert_eq!(
+            sequence_matcher.push(key),
+            Ok(SequenceMatcherEvent::NoMatch)
+        );
+    }
+    assert_eq!(sequence_matcher.len(), 3);
+
+    for _ in 0..2 {
+        MockTimestamp::now();
+    }
+
+    for key in [Key::Down, Key::Ok] {
+        assert_eq!(
+            sequence_matcher.push(key),
+            Ok(SequenceMatcherEvent::NoMatch)
+        );
+    }
+    assert_eq!(sequence_matcher.len(), 2);
+}
+
+#[test]
+fn sequence_matcher_idle_events() {
+    let mut sequence_matcher = TestEncoderSequenceMatcher::new();
+
+    let events = [
+        EncoderEvent::ClockwiseTurn,
+        EncoderEvent::NoTurn,
+        EncoderEvent::NoTurn,
+        EncoderEvent::NoTurn,
+        EncoderEvent::NoTurn,
+    ];
+
+    for event in events {
+        assert_eq!(
+            sequence_matcher.push(event),
+            Ok(SequenceMatcherEvent::NoMatch)
+        );
+    }
+    assert_eq!(sequence_matcher.len(), 1);
+
+    assert_eq!(
+        sequence_matcher.push(EncoderEvent::CounterClockwiseTurn),
+        Ok(SequenceMatcherEvent::SequenceMatched(0))
+    );
+}
+
+#[test]
+#[should_panic]
+fn sequence_matcher_pattern_longer_than_len() {
+    SequenceMatcher::<TestSequenceMatcherConfig, 4>::new();
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use embedded_controls::{DebouncedInputEvent, DebouncedInputState, EncoderEvent, Error};
+
+#[test]
+fn events_round_trip() {
+    for event in [
+        DebouncedInputEvent::Low,
+        DebouncedInputEvent::High,
+        DebouncedInputEvent::Rise,
+        DebouncedInputEvent::Fall,
+    ] {
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DebouncedInputEvent>(&json).unwrap(),
+            event
+        );
+    }
+
+    for event in [
+        EncoderEvent::NoTurn,
+        EncoderEvent::ClockwiseTurn,
+        EncoderEvent::CounterClockwiseTurn,
+    ] {
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<EncoderEvent>(&json).unwrap(), event);
+    }
+}
+
+#[test]
+fn state_and_error_round_trip() {
+    let state = DebouncedInputState::RiseDisturbance(42u32);
+    let json = serde_json::to_string(&state).unwrap();
+    assert!(matches!(
+        serde_json::from_str::<DebouncedInputState<u32>>(&json).unwrap(),
+        DebouncedInputState::RiseDisturbance(42)
+    ));
+
+    let state = DebouncedInputState::Settling(true, 7u32);
+    let json = serde_json::to_string(&state).unwrap();
+    assert!(matches!(
+        serde_json::from_str::<DebouncedInputState<u32>>(&json).unwrap(),
+        DebouncedInputState::Settling(true, 7)
+    ));
+
+    for error in [Error::ElapsedTimer(1u8), Error::InputSwitch(2u8)] {
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(serde_json::from_str::<Error<u8, u8>>(&json).unwrap(), error);
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "simulation")]
+
+use embedded_controls::{
+    debounced_input_config, encoder_config,
+    simulation::{
+        bouncing_waveform, compare_events, debounced_input_events, quadrature_waveforms, simulate,
+        BounceProfile, QuadratureSegment, XorShiftRng,
+    },
+    testing::{ManualClock, ManualTimestamp, ScriptedInputSwitch},
+    DebouncedInput, DebouncedInputEvent, Encoder, EncoderEvent,
+};
+use timestamp_source::Timer;
+
+debounced_input_config!(
+    TestDebouncedInputConfig,
+    debounce_timer: Timer<ManualTimestamp> = Timer::new(20)
+);
+
+debounced_input_config!(
+    TestShortDebouncedInputConfig,
+    debounce_timer: Timer<ManualTimestamp> = Timer::new(1)
+);
+
+encoder_config!(
+    TestEncoderConfig,
+    debounce_timer: Timer<ManualTimestamp> = Timer::new(8),
+    counts_div: i8 = 4
+);
+
+const BOUNCE: BounceProfile = BounceProfile {
+    bounces: 4,
+    duration: 12,
+    jitter: 3,
+};
+
+const EDGES: [(u64, bool); 4] = [(100, true), (300, false), (500, true), (700, false)];
+
+const IDLE: [DebouncedInputEvent; 2] = [DebouncedInputEvent::Low, DebouncedInputEvent::High];
+
+#[test]
+fn simulation_debounced_input() {
+    ManualClock::reset();
+
+    let mut rng = XorShiftRng::new(42);
+    let input_switch = ScriptedInputSwitch::new(bouncing_waveform(&EDGES, &BOUNCE, &mut rng));
+    let mut debounced_input = DebouncedInput::<_, TestDebouncedInputConfig>::new(input_switch);
+
+    let detected = simulate(&mut debounced_input, 1000, 1).unwrap();
+    let report = compare_events(&debounced_input_events(&EDGES), &detected, &IDLE, 50);
+
+    assert!(report.is_clean(), "{:?}", report);
+    assert_eq!(report.matched, 4);
+    assert!(report.max_latency >= 20);
+}
+
+#[test]
+fn simulation_debounced_input_too_short_debounce() {
+    ManualClock::reset();
+
+    let mut rng = XorShiftRng::new(42);
+    let input_switch = ScriptedInputSwitch::new(bouncing_waveform(&EDGES, &BOUNCE, &mut rng));
+    let mut debounced_input = DebouncedInput::<_, TestShortDebouncedInputConfig>::new(input_switch);
+
+    let detected = simulate(&mut debounced_input, 1000, 1).unwrap();
+    let report = compare_events(&debounced_input_events(&EDGES), &detected, &IDLE, 50);
+
+    assert!(!report.is_clean());
+    assert!(!report.extra.is_empty());
+}
+
+#[test]
+fn simulation_encoder() {
+    ManualClock::reset();
+
+    let mut rng = XorShiftRng::new(7);
+    let noise = BounceProfile {
+        bounces: 2,
+        duration: 4,
+        jitter: 1,
+    };
+    let segments = [
+        QuadratureSegment {
+            steps: 8,
+            period: 40,
+        },
+        QuadratureSegment {
+            steps: -12,
+            period: 25,
+        },
+    ];
+    let quadrature = quadrature_waveforms(50, &segments, 4, &noise, &mut rng);
+
+    let mut encoder = Encoder::<_, _, TestEncoderConfig>::new(
+        ScriptedInputSwitch::new(quadrature.a),
+        ScriptedInputSwitch::new(quadrature.b),
+    );
+
+    let detected = simulate(&mut encoder, 1000, 1).unwrap();
+    let report = compare_events(&quadrature.expected, &detected, &[EncoderEvent::NoTurn], 20);
+
+    assert!(report.is_clean(), "{:?}", report);
+    assert_eq!(report.matched, 5);
+}
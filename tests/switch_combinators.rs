@@ -0,0 +1,102 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    debounced_input_config, AllOf, AnyOf, CombinedSwitchError, Control, DebouncedInput,
+    DebouncedInputEvent, Error, Inverted, Xor,
+};
+use switch_hal::InputSwitch;
+use timestamp_source::Timer;
+
+debounced_input_config!(
+    TestDebouncedInputConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1)
+);
+
+type TestDebouncedInput<Switch> = DebouncedInput<Switch, TestDebouncedInputConfig>;
+
+const STATES_A: [Result<bool, &str>; 4] = [Ok(false), Ok(false), Ok(true), Ok(true)];
+const STATES_B: [Result<bool, &str>; 4] = [Ok(false), Ok(true), Ok(false), Ok(true)];
+
+#[test]
+fn switch_combinators_success() {
+    let inverted = Inverted::new(MockInputSwitch::new(&STATES_A));
+    let any_of = AnyOf::new(
+        MockInputSwitch::new(&STATES_A),
+        MockInputSwitch::new(&STATES_B),
+    );
+    let all_of = AllOf::new(
+        MockInputSwitch::new(&STATES_A),
+        MockInputSwitch::new(&STATES_B),
+    );
+    let xor = Xor::new(
+        MockInputSwitch::new(&STATES_A),
+        MockInputSwitch::new(&STATES_B),
+    );
+
+    for expected in [true, true, false, false] {
+        assert_eq!(inverted.is_active(), Ok(expected));
+    }
+    for expected in [false, true, true, true] {
+        assert_eq!(any_of.is_active(), Ok(expected));
+    }
+    for expected in [false, false, false, true] {
+        assert_eq!(all_of.is_active(), Ok(expected));
+    }
+    for expected in [false, true, true, false] {
+        assert_eq!(xor.is_active(), Ok(expected));
+    }
+}
+
+#[test]
+fn switch_combinators_debounced_input() {
+    let state_results_a = [Ok(true), Ok(true), Ok(false), Ok(false), Ok(false)];
+    let state_results_b = [Ok(false), Ok(false), Ok(false), Ok(false), Ok(true)];
+
+    let any_of = AnyOf::new(
+        Inverted::new(MockInputSwitch::new(&state_results_a)),
+        MockInputSwitch::new(&state_results_b),
+    );
+    let mut debounced_input = TestDebouncedInput::new(any_of);
+
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Rise));
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::High));
+}
+
+#[test]
+fn switch_combinators_error() {
+    let state_results_a = [
+        Ok(false),
+        Err("Some error 0"),
+        Err("Some error 2"),
+        Ok(false),
+    ];
+    let state_results_b = [
+        Err("Some error 1"),
+        Ok(false),
+        Err("Some error 3"),
+        Ok(false),
+    ];
+
+    let xor = Xor::new(
+        MockInputSwitch::new(&state_results_a),
+        MockInputSwitch::new(&state_results_b),
+    );
+    let mut debounced_input = TestDebouncedInput::new(Inverted::new(xor));
+
+    assert_eq!(
+        debounced_input.update(),
+        Err(Error::InputSwitch(CombinedSwitchError::A("Some error 0")))
+    );
+    assert_eq!(
+        debounced_input.update(),
+        Err(Error::InputSwitch(CombinedSwitchError::Both(
+            "Some error 2",
+            "Some error 3"
+        )))
+    );
+    assert_eq!(debounced_input.update(), Ok(DebouncedInputEvent::Low));
+}
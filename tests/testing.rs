@@ -0,0 +1,64 @@
+#![cfg(feature = "testing")]
+
+use embedded_controls::{
+    debounced_input_config,
+    testing::{assert_event_sequence, run_for, ManualClock, ManualTimestamp, ScriptedInputSwitch},
+    Control, DebouncedInput, DebouncedInputEvent, Error,
+};
+use timestamp_source::{Timer, Timestamp};
+
+debounced_input_config!(
+    TestDebouncedInputConfig,
+    debounce_timer: Timer<ManualTimestamp> = Timer::new(3)
+);
+
+type TestDebouncedInput<Switch> = DebouncedInput<Switch, TestDebouncedInputConfig>;
+
+#[test]
+fn manual_clock() {
+    ManualClock::reset();
+    let start = ManualTimestamp::now();
+
+    ManualClock::advance(5);
+    let end = ManualTimestamp::now();
+
+    assert_eq!(end.ticks(), 5);
+    assert_eq!(end.duration_since(&start), Ok(5));
+    assert_eq!(start.duration_since(&end), Err(()));
+}
+
+#[test]
+fn scripted_input_switch_with_bounce() {
+    ManualClock::reset();
+
+    let input_switch = ScriptedInputSwitch::new([(5, true), (6, false), (7, true), (20, false)]);
+    let mut debounced_input = TestDebouncedInput::new(input_switch);
+
+    let events = run_for(&mut debounced_input, 30, 1).unwrap();
+
+    assert_event_sequence(
+        &events,
+        &[DebouncedInputEvent::Low, DebouncedInputEvent::High],
+        &[DebouncedInputEvent::Rise, DebouncedInputEvent::Fall],
+    );
+    assert_eq!(events[10], DebouncedInputEvent::Rise);
+    assert_eq!(events[23], DebouncedInputEvent::Fall);
+}
+
+#[test]
+fn scripted_input_switch_error() {
+    ManualClock::reset();
+
+    let input_switch = ScriptedInputSwitch::with_results([(0, Ok(false)), (2, Err("Some error"))]);
+    let mut debounced_input = TestDebouncedInput::new(input_switch);
+
+    assert_eq!(
+        run_for(&mut debounced_input, 5, 1),
+        Err(Error::InputSwitch("Some error"))
+    );
+    assert_eq!(ManualClock::ticks(), 2);
+    assert_eq!(
+        debounced_input.update(),
+        Err(Error::InputSwitch("Some error"))
+    );
+}
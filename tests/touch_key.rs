@@ -0,0 +1,105 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockOutputSwitch, MockTimestamp};
+
+use embedded_controls::{touch_key_config, Control, DebouncedInputEvent, TouchKey, TouchKeyError};
+use timestamp_source::Timer;
+
+touch_key_config!(
+    TestTouchKeyConfig,
+    measure_timeout_timer: Timer<MockTimestamp> = Timer::new(10),
+    touch_threshold: 5,
+    release_threshold: 3,
+    baseline_shift: 2
+);
+
+type TestTouchKey<Output, Input> = TouchKey<Output, Input, TestTouchKeyConfig>;
+
+fn charge(charge_time: usize) -> impl Iterator<Item = Result<bool, &'static str>> {
+    (0..charge_time)
+        .map(|_| Ok(false))
+        .chain(core::iter::once(Ok(true)))
+}
+
+#[test]
+fn touch_key_success() {
+    let state_results: Vec<_> = [2, 2, 8, 8, 4, 2, 20]
+        .into_iter()
+        .flat_map(charge)
+        .collect();
+
+    let output_switch = MockOutputSwitch::new();
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut touch_key = TestTouchKey::new(output_switch, input_switch);
+
+    assert_eq!(touch_key.baseline(), None);
+
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(touch_key.baseline(), Some(3));
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Low));
+
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Rise));
+    assert!(touch_key.is_touched());
+    assert_eq!(touch_key.charge_time(), 9);
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::High));
+    assert_eq!(touch_key.baseline(), Some(3));
+
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Fall));
+    assert!(!touch_key.is_touched());
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Low));
+
+    // measurement is aborted by timeout
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Rise));
+    assert_eq!(touch_key.charge_time(), 10);
+
+    let (output_switch, _) = touch_key.release_switches();
+    assert!(!output_switch.is_on());
+}
+
+#[test]
+fn touch_key_error() {
+    let output_results = [Ok(()), Err("Some error 0"), Ok(()), Ok(()), Ok(()), Ok(())];
+    let state_results = [Err("Some error 1")];
+
+    let output_switch = MockOutputSwitch::with_results(&output_results);
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut touch_key = TestTouchKey::new(output_switch, input_switch);
+
+    assert_eq!(
+        touch_key.update(),
+        Err(TouchKeyError::OutputSwitch("Some error 0"))
+    );
+    assert_eq!(
+        touch_key.update(),
+        Err(TouchKeyError::InputSwitch("Some error 1"))
+    );
+
+    let (output_switch, _) = touch_key.release_switches();
+    assert!(!output_switch.is_on());
+}
+
+#[test]
+fn touch_key_recovery() {
+    let state_results: Vec<_> = core::iter::once(Err("Some error 1"))
+        .chain(charge(2))
+        .chain(charge(2))
+        .collect();
+
+    let output_switch = MockOutputSwitch::new();
+    let input_switch = MockInputSwitch::new(&state_results);
+    let mut touch_key = TestTouchKey::new(output_switch, input_switch);
+
+    assert_eq!(
+        touch_key.update(),
+        Err(TouchKeyError::InputSwitch("Some error 1"))
+    );
+    assert_eq!(touch_key.baseline(), None);
+    assert!(!touch_key.is_touched());
+
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Low));
+    assert_eq!(touch_key.baseline(), Some(3));
+    assert_eq!(touch_key.update(), Ok(DebouncedInputEvent::Low));
+
+    let (output_switch, _) = touch_key.release_switches();
+    assert!(!output_switch.is_on());
+}
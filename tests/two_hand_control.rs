@@ -0,0 +1,231 @@
+mod common;
+
+use crate::common::{MockInputSwitch, MockTimestamp};
+
+use embedded_controls::{
+    two_hand_control_config, Control, Error, RawLevels, TwoHandControl, TwoHandControlEvent,
+};
+use timestamp_source::Timer;
+
+two_hand_control_config!(
+    TestTwoHandControlConfig,
+    debounce_timer: Timer<MockTimestamp> = Timer::new(1),
+    simultaneity_timer: Timer<MockTimestamp> = Timer::new(7)
+);
+
+type TestTwoHandControl<Switch> = TwoHandControl<Switch, TestTwoHandControlConfig>;
+
+#[test]
+fn two_hand_control_enabled() {
+    let state_results_left = [
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let state_results_right = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let mut two_hand_control = TestTwoHandControl::new(
+        MockInputSwitch::new(&state_results_left),
+        MockInputSwitch::new(&state_results_right),
+    );
+
+    for _ in 0..3 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+    }
+
+    for _ in 0..3 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Enabled));
+        assert!(two_hand_control.is_enabled());
+    }
+
+    for _ in 0..2 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+        assert!(!two_hand_control.is_enabled());
+    }
+}
+
+#[test]
+fn two_hand_control_fault() {
+    let state_results_left = [
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let state_results_right = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let mut two_hand_control = TestTwoHandControl::new(
+        MockInputSwitch::new(&state_results_left),
+        MockInputSwitch::new(&state_results_right),
+    );
+
+    for _ in 0..5 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+    }
+
+    for _ in 0..3 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Fault));
+        assert!(two_hand_control.is_fault());
+        assert!(!two_hand_control.is_enabled());
+    }
+
+    for _ in 0..2 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+        assert!(!two_hand_control.is_fault());
+    }
+}
+
+#[test]
+fn two_hand_control_late_second_press() {
+    let state_results_left = [
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+    let state_results_right = [
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(false),
+    ];
+
+    let mut two_hand_control = TestTwoHandControl::new(
+        MockInputSwitch::new(&state_results_left),
+        MockInputSwitch::new(&state_results_right),
+    );
+
+    for _ in 0..4 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+    }
+
+    // the second press arrives after the simultaneity window is elapsed
+    for _ in 0..3 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Fault));
+        assert!(!two_hand_control.is_enabled());
+    }
+
+    for _ in 0..2 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+        assert!(!two_hand_control.is_fault());
+    }
+}
+
+#[test]
+fn two_hand_control_error() {
+    let state_results_left = [
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Err("Some error"),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+    ];
+
+    let state_results_right = [
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(false),
+        Ok(false),
+        Ok(true),
+        Ok(true),
+        Ok(true),
+    ];
+
+    let mut two_hand_control = TestTwoHandControl::new(
+        MockInputSwitch::new(&state_results_left),
+        MockInputSwitch::new(&state_results_right),
+    );
+
+    for _ in 0..2 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+    }
+
+    for _ in 0..2 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Enabled));
+        assert!(two_hand_control.is_enabled());
+    }
+
+    assert_eq!(
+        two_hand_control.update(),
+        Err(Error::InputSwitch("Some error"))
+    );
+    assert!(!two_hand_control.is_enabled());
+    assert_eq!(
+        two_hand_control.borrow_debounced_inputs()[1].raw_levels(),
+        0
+    );
+
+    // both buttons are held, but must be released to re-arm the control after the error
+    for _ in 0..2 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+        assert!(!two_hand_control.is_enabled());
+    }
+
+    for _ in 0..3 {
+        assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Disabled));
+    }
+    assert_eq!(two_hand_control.update(), Ok(TwoHandControlEvent::Enabled));
+}